@@ -3,34 +3,58 @@ use multiversx_sc::imports::*;
 
 pub mod bridge_proxy_contract_proxy;
 pub mod bridged_tokens_wrapper_proxy;
+pub mod call_allowlist;
 pub mod config;
 pub mod esdt_safe_proxy;
+pub mod tx_status;
 
-use transaction::{CallData, EthTransaction};
-const MIN_GAS_LIMIT_FOR_SC_CALL: u64 = 10_000_000;
-const MAX_GAS_LIMIT_FOR_SC_CALL: u64 = 249999999;
+use token_module::PERCENTAGE_TOTAL;
+use transaction::{
+    call_data_validation::CallDataError, multi_call_data::MultiCallData,
+    refund_reason::RefundReason, CallData, EthTransaction, PaymentsVec,
+};
+use tx_status::ExecutionStatus;
+const DEFAULT_MIN_GAS_LIMIT_FOR_SC_CALL: u64 = 10_000_000;
+const DEFAULT_MAX_GAS_LIMIT_FOR_SC_CALL: u64 = 249999999;
 const DEFAULT_GAS_LIMIT_FOR_REFUND_CALLBACK: u64 = 20_000_000; // 20 million
 const DELAY_BEFORE_OWNER_CAN_CANCEL_TRANSACTION: u64 = 300;
+const DELAY_BEFORE_OWNER_CAN_RECOVER_EXECUTION: u64 = 300;
+const MULTI_CALL_GAS_OVERHEAD: u64 = 5_000_000;
+const MAX_PENDING_TRANSACTIONS_PAGE_SIZE: usize = 100;
 
 #[multiversx_sc::contract]
 pub trait BridgeProxyContract:
     config::ConfigModule
+    + call_allowlist::CallAllowlistModule
+    + fee_estimator_module::FeeEstimatorModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[init]
     fn init(&self, opt_multi_transfer_address: OptionalValue<ManagedAddress>) {
         self.set_multi_transfer_contract_address(opt_multi_transfer_address);
+        self.min_gas_limit_for_sc_call()
+            .set_if_empty(DEFAULT_MIN_GAS_LIMIT_FOR_SC_CALL);
+        self.max_gas_limit_for_sc_call()
+            .set_if_empty(DEFAULT_MAX_GAS_LIMIT_FOR_SC_CALL);
         self.set_paused(true);
     }
 
     #[upgrade]
     fn upgrade(&self) {
+        self.min_gas_limit_for_sc_call()
+            .set_if_empty(DEFAULT_MIN_GAS_LIMIT_FOR_SC_CALL);
+        self.max_gas_limit_for_sc_call()
+            .set_if_empty(DEFAULT_MAX_GAS_LIMIT_FOR_SC_CALL);
+        // transactions deposited before the deposit round was recorded count as deposited now
+        self.legacy_deposit_round()
+            .set_if_empty(self.blockchain().get_block_round());
+
         self.set_paused(true);
     }
 
     #[payable("*")]
     #[endpoint]
-    fn deposit(&self, eth_tx: EthTransaction<Self::Api>, batch_id: u64) {
+    fn deposit(&self, eth_tx: EthTransaction<Self::Api>, batch_id: u64) -> usize {
         self.require_not_paused();
         let caller = self.blockchain().get_caller();
         let payment = self.call_value().single_esdt();
@@ -39,9 +63,15 @@ pub trait BridgeProxyContract:
             "Only MultiTransfer can do deposits"
         );
         let next_tx_id = self.get_next_tx_id();
-        self.pending_transactions().insert(next_tx_id, eth_tx);
         self.payments(next_tx_id).set(&payment);
         self.batch_id(next_tx_id).set(batch_id);
+        self.index_pending_transaction(next_tx_id, &eth_tx);
+        self.pending_transactions().insert(next_tx_id, eth_tx);
+        self.deposit_round(next_tx_id)
+            .set(self.blockchain().get_block_round());
+        self.set_transaction_status(next_tx_id, ExecutionStatus::Pending);
+
+        next_tx_id
     }
 
     #[endpoint(execute)]
@@ -56,11 +86,25 @@ pub trait BridgeProxyContract:
 
         require!(payment.amount != 0, "No amount bridged");
 
+        // the contract must never call itself on behalf of a user
+        if tx.to == self.blockchain().get_sc_address() {
+            self.reject_call_data(tx_id, CallDataError::InvalidTarget);
+            return;
+        }
+
+        if tx.call_data.is_some() {
+            let raw_call_data = unsafe { tx.call_data.clone().unwrap_no_check() };
+            if MultiCallData::is_multi_call(&raw_call_data) {
+                self.execute_multi_call(tx_id, payment, raw_call_data);
+                return;
+            }
+        }
+
         let call_data: CallData<Self::Api> = if tx.call_data.is_some() {
             let unwraped_call_data = unsafe { tx.call_data.unwrap_no_check() };
 
             let Ok(call_data) = CallData::top_decode(unwraped_call_data) else {
-                self.finish_execute_gracefully(tx_id);
+                self.reject_call_data(tx_id, CallDataError::UndecodableCallData);
                 return;
             };
 
@@ -69,11 +113,15 @@ pub trait BridgeProxyContract:
             CallData::default()
         };
 
-        if call_data.endpoint.is_empty()
-            || call_data.gas_limit < MIN_GAS_LIMIT_FOR_SC_CALL
-            || call_data.gas_limit > MAX_GAS_LIMIT_FOR_SC_CALL
-        {
-            self.finish_execute_gracefully(tx_id);
+        if let Err(error) = call_data.validate(
+            self.min_gas_limit_for_sc_call().get(),
+            self.max_gas_limit_for_sc_call().get(),
+        ) {
+            self.reject_call_data(tx_id, error);
+            return;
+        }
+        if !self.is_call_allowed(&tx.to, &call_data.endpoint) {
+            self.reject_call_data(tx_id, CallDataError::CallNotAllowed);
             return;
         }
 
@@ -83,6 +131,13 @@ pub trait BridgeProxyContract:
             "Not enough gas to execute"
         );
 
+        let execution_gas_fee = self.get_execution_gas_fee(&payment, call_data.gas_limit);
+        if execution_gas_fee >= payment.amount {
+            self.reject_execution_gas_fee(tx_id, &execution_gas_fee);
+            return;
+        }
+        let payment = self.hold_executor_fees(tx_id, payment, execution_gas_fee);
+
         let tx_call = self
             .tx()
             .to(&tx.to)
@@ -101,48 +156,229 @@ pub trait BridgeProxyContract:
 
         let block_round = self.blockchain().get_block_round();
         self.ongoing_execution(tx_id).set(block_round);
+        self.set_transaction_status(tx_id, ExecutionStatus::Executing);
         tx_call.register_promise();
     }
 
-    // TODO: will activate endpoint in a future release
-    // #[endpoint(cancel)]
-    fn cancel(&self, tx_id: usize) {
-        let tx_start_round = self.ongoing_execution(tx_id).get();
+    /// Cancels a pending transaction, either delivering the payment to the recipient
+    /// or refunding it to Ethereum.
+    /// Can only be called by the owner or the recipient, after a delay counted from the deposit.
+    /// Transactions being executed can not be cancelled, as the payment was already sent along with the call,
+    /// see `recoverStuckExecution`.
+    #[endpoint(cancel)]
+    fn cancel(&self, tx_id: usize, refund_to_ethereum: bool) {
+        self.require_not_paused();
+        let tx = self.get_pending_transaction_by_id(tx_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == tx.to || caller == self.blockchain().get_owner_address(),
+            "Only owner or recipient can cancel the transaction"
+        );
+        require!(
+            self.transaction_status(tx_id).get() != ExecutionStatus::Executing,
+            "Transaction is being executed"
+        );
+
+        let deposit_round = if self.deposit_round(tx_id).is_empty() {
+            self.legacy_deposit_round().get()
+        } else {
+            self.deposit_round(tx_id).get()
+        };
         let current_block_round = self.blockchain().get_block_round();
         require!(
-            current_block_round - tx_start_round > DELAY_BEFORE_OWNER_CAN_CANCEL_TRANSACTION,
+            current_block_round.saturating_sub(deposit_round)
+                > DELAY_BEFORE_OWNER_CAN_CANCEL_TRANSACTION,
             "Transaction can't be cancelled yet"
         );
 
+        if refund_to_ethereum {
+            self.refund_transaction(tx_id, RefundReason::ProxyTransactionCancelled);
+        } else {
+            let payment = self.payments(tx_id).get();
+            self.tx().to(&tx.to).payment(payment).transfer();
+        }
+        self.cleanup_transaction(tx_id);
+        self.set_transaction_status(tx_id, ExecutionStatus::Cancelled);
+
+        self.cancel_transaction_event(tx_id, &caller, refund_to_ethereum);
+    }
+
+    /// Recovers a transaction whose execution callback never completed, leaving it stuck as executing,
+    /// either delivering the payment to the recipient or refunding it to Ethereum.
+    /// The executor is not paid. A callback arriving afterwards is ignored.
+    ///
+    /// The payment is only back in the contract if the call itself failed,
+    /// so the owner must check the outcome of the call before recovering it.
+    #[only_owner]
+    #[endpoint(recoverStuckExecution)]
+    fn recover_stuck_execution(&self, tx_id: usize, refund_to_ethereum: bool) {
         let tx = self.get_pending_transaction_by_id(tx_id);
-        let payment = self.payments(tx_id).get();
-        self.tx().to(tx.to).payment(payment).transfer();
+        require!(
+            self.transaction_status(tx_id).get() == ExecutionStatus::Executing,
+            "Transaction is not being executed"
+        );
+        let current_block_round = self.blockchain().get_block_round();
+        require!(
+            current_block_round.saturating_sub(self.ongoing_execution(tx_id).get())
+                > DELAY_BEFORE_OWNER_CAN_RECOVER_EXECUTION,
+            "Execution can't be recovered yet"
+        );
+
+        let mut payment = self.payments(tx_id).get();
+        payment.amount += self.held_executor_fee(tx_id).take();
+        self.payments(tx_id).set(&payment);
+
+        if refund_to_ethereum {
+            self.refund_transaction(tx_id, RefundReason::ProxyTransactionCancelled);
+        } else {
+            self.tx().to(&tx.to).payment(payment).transfer();
+        }
         self.cleanup_transaction(tx_id);
+        self.set_transaction_status(tx_id, ExecutionStatus::Cancelled);
+
+        self.stuck_execution_recovered_event(tx_id, refund_to_ethereum);
     }
+
+    /// Executes the steps of a multi-call, forwarding the tokens sent back by each step to the next one.
+    /// Only callable by the contract itself, through `execute`, so a failing step reverts the whole sequence.
+    /// The steps run at most once per execution.
+    #[endpoint(executeCalls)]
+    fn execute_calls(&self, tx_id: usize) {
+        require!(
+            self.blockchain().get_caller() == self.blockchain().get_sc_address(),
+            "Only the contract itself can execute calls"
+        );
+        require!(self.pending_multi_call(tx_id).take(), "No calls to execute");
+
+        let tx = self.get_pending_transaction_by_id(tx_id);
+        require!(tx.call_data.is_some(), "Invalid call data");
+        let raw_call_data = unsafe { tx.call_data.clone().unwrap_no_check() };
+        let Ok(multi_call_data) = MultiCallData::<Self::Api>::top_decode(raw_call_data) else {
+            sc_panic!("Invalid call data");
+        };
+
+        let mut payments = PaymentsVec::from_single_item(self.payments(tx_id).get());
+        for step in multi_call_data.calls.iter() {
+            let tx_call = self
+                .tx()
+                .to(&step.to)
+                .raw_call(step.call_data.endpoint.clone())
+                .gas(step.call_data.gas_limit)
+                .payment(payments);
+
+            let tx_call = if step.call_data.args.is_some() {
+                let args = unsafe { step.call_data.args.clone().unwrap_no_check() };
+                tx_call.arguments_raw(args.into())
+            } else {
+                tx_call
+            };
+
+            let back_transfers = tx_call.returns(ReturnsBackTransfers).sync_call();
+            payments = back_transfers.esdt_payments;
+        }
+
+        self.forward_back_transfers(tx_id, &tx, multi_call_data.fallback_address, payments);
+    }
+
     #[promises_callback]
-    fn execution_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>, tx_id: usize) {
-        if result.is_err() {
-            self.refund_transaction(tx_id);
+    fn execution_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+        tx_id: usize,
+    ) {
+        // the execution was recovered in the meantime
+        if self.transaction_status(tx_id).get() != ExecutionStatus::Executing {
+            self.late_execution_callback_event(tx_id);
+            return;
+        }
+
+        match result {
+            ManagedAsyncCallResult::Ok(results) => {
+                self.execution_result_event(tx_id, results.to_vec());
+                self.pay_held_executor_fee(tx_id);
+
+                // tokens sent back by the target contract
+                let tx = self.get_pending_transaction_by_id(tx_id);
+                let back_transfers = self.call_value().all_esdt_transfers().clone_value();
+                self.forward_back_transfers(tx_id, &tx, ManagedOption::none(), back_transfers);
+
+                self.set_transaction_status(tx_id, ExecutionStatus::Succeeded);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                self.execution_failed_event(tx_id, err.err_code, &err.err_msg);
+
+                // the executor is only paid for successful executions
+                let tx = self.get_pending_transaction_by_id(tx_id);
+                let mut payment = self.payments(tx_id).get();
+                payment.amount += self.held_executor_fee(tx_id).take();
+                self.executor(tx_id).clear();
+                self.set_aside_payments(
+                    tx_id,
+                    &tx,
+                    PaymentsVec::from_single_item(payment),
+                    RefundReason::ProxyExecutionFailed,
+                );
+                self.set_transaction_status(tx_id, ExecutionStatus::RefundPending);
+            }
         }
         self.cleanup_transaction(tx_id);
     }
 
-    fn refund_transaction(&self, tx_id: usize) {
+    /// Bridges back to Ethereum the tokens set aside after an execution,
+    /// i.e. the payment of a failed call or the tokens sent back by a successful one.
+    /// Can be called by anyone, and retried until the EsdtSafe accepts the tokens.
+    #[endpoint(settleTransaction)]
+    fn settle_transaction(&self, tx_id: usize) {
+        self.require_not_paused();
+        let tx = match self.unsettled_transactions().remove(&tx_id) {
+            Some(tx) => tx,
+            None => sc_panic!("Nothing to settle"),
+        };
+
+        // payments set aside before the reason was stored come from failed executions
+        let refund_reason = match self.unsettled_refund_reason(tx_id).take() {
+            RefundReason::None => RefundReason::ProxyExecutionFailed,
+            refund_reason => refund_reason,
+        };
+        for payment in self.unsettled_payments(tx_id).take().iter() {
+            self.refund_payment(tx_id, &tx, payment, refund_reason);
+        }
+        if self.transaction_status(tx_id).get() == ExecutionStatus::RefundPending {
+            self.set_transaction_status(tx_id, ExecutionStatus::Refunded);
+        }
+
+        self.transaction_settled_event(tx_id);
+    }
+
+    fn refund_transaction(&self, tx_id: usize, refund_reason: RefundReason) {
         let tx = self.get_pending_transaction_by_id(tx_id);
+        self.refund_payment(tx_id, &tx, self.payments(tx_id).get(), refund_reason);
+    }
+
+    /// The recipient is used as the refund address, so the tokens are never credited to this contract,
+    /// even if the transaction is rejected on the Ethereum side.
+    fn refund_payment(
+        &self,
+        tx_id: usize,
+        tx: &EthTransaction<Self::Api>,
+        payment: EsdtTokenPayment,
+        refund_reason: RefundReason,
+    ) {
         let esdt_safe_contract_address = self.esdt_safe_contract_address().get();
 
-        let unwrapped_token = self.unwrap_token(&tx.token_id, tx_id);
+        let unwrapped_token = self.unwrap_token(&tx.token_id, payment);
         let batch_id = self.batch_id(tx_id).get();
         self.tx()
             .to(esdt_safe_contract_address)
             .typed(esdt_safe_proxy::EsdtSafeProxy)
             .create_transaction(
-                tx.from,
+                tx.from.clone(),
                 OptionalValue::Some(esdt_safe_proxy::RefundInfo {
-                    address: tx.to,
+                    address: tx.to.clone(),
                     initial_batch_id: batch_id,
                     initial_nonce: tx.tx_nonce,
                 }),
+                OptionalValue::Some(refund_reason),
             )
             .single_esdt(
                 &unwrapped_token.token_identifier,
@@ -152,8 +388,90 @@ pub trait BridgeProxyContract:
             .sync_call();
     }
 
-    fn unwrap_token(&self, requested_token: &TokenIdentifier, tx_id: usize) -> EsdtTokenPayment {
-        let payment = self.payments(tx_id).get();
+    /// Bridging the tokens back from the execution callback or during a MultiTransfer batch
+    /// could fail and revert it, so they are kept until `settleTransaction` is called.
+    fn set_aside_payments(
+        &self,
+        tx_id: usize,
+        tx: &EthTransaction<Self::Api>,
+        payments: PaymentsVec<Self::Api>,
+        refund_reason: RefundReason,
+    ) {
+        if payments.is_empty() {
+            return;
+        }
+
+        self.unsettled_transactions().insert(tx_id, tx.clone());
+        self.unsettled_payments(tx_id).set(&payments);
+        self.unsettled_refund_reason(tx_id).set(refund_reason);
+
+        self.payments_set_aside_event(tx_id, &payments);
+    }
+
+    /// Sends the tokens left over after an execution to the fallback address, if one was provided.
+    /// Otherwise, the bridged token is set aside, to be bridged back to the Ethereum sender,
+    /// and any other token is sent to the recipient, as it can not be bridged.
+    fn forward_back_transfers(
+        &self,
+        tx_id: usize,
+        tx: &EthTransaction<Self::Api>,
+        fallback_address: ManagedOption<Self::Api, ManagedAddress>,
+        back_transfers: PaymentsVec<Self::Api>,
+    ) {
+        if fallback_address.is_some() {
+            let fallback_address = unsafe { fallback_address.unwrap_no_check() };
+            for payment in back_transfers.iter() {
+                self.back_transfer_forwarded_event(
+                    tx_id,
+                    &fallback_address,
+                    &payment.token_identifier,
+                    &payment.amount,
+                );
+            }
+            if !back_transfers.is_empty() {
+                self.tx()
+                    .to(&fallback_address)
+                    .payment(back_transfers)
+                    .transfer();
+            }
+            return;
+        }
+
+        let bridged_token = self.payments(tx_id).get().token_identifier;
+        let mut refundable_payments = PaymentsVec::new();
+        let mut other_payments = PaymentsVec::new();
+        for payment in back_transfers.iter() {
+            if payment.token_identifier != bridged_token && payment.token_identifier != tx.token_id
+            {
+                self.back_transfer_forwarded_event(
+                    tx_id,
+                    &tx.to,
+                    &payment.token_identifier,
+                    &payment.amount,
+                );
+                other_payments.push(payment);
+                continue;
+            }
+
+            refundable_payments.push(payment);
+        }
+
+        if !other_payments.is_empty() {
+            self.tx().to(&tx.to).payment(other_payments).transfer();
+        }
+        self.set_aside_payments(
+            tx_id,
+            tx,
+            refundable_payments,
+            RefundReason::ProxyBackTransfersRefunded,
+        );
+    }
+
+    fn unwrap_token(
+        &self,
+        requested_token: &TokenIdentifier,
+        payment: EsdtTokenPayment,
+    ) -> EsdtTokenPayment {
         let bridged_tokens_wrapper_address = self.bridged_tokens_wrapper_address().get();
 
         if requested_token == &payment.token_identifier {
@@ -184,14 +502,193 @@ pub trait BridgeProxyContract:
         transfers.esdt_payments.get(0)
     }
 
+    fn execute_multi_call(
+        &self,
+        tx_id: usize,
+        payment: EsdtTokenPayment,
+        raw_call_data: ManagedBuffer,
+    ) {
+        let Ok(multi_call_data) = MultiCallData::<Self::Api>::top_decode(raw_call_data) else {
+            self.reject_call_data(tx_id, CallDataError::UndecodableCallData);
+            return;
+        };
+
+        if let Err(error) = multi_call_data.validate(
+            self.min_gas_limit_for_sc_call().get(),
+            self.max_gas_limit_for_sc_call().get(),
+        ) {
+            self.reject_call_data(tx_id, error);
+            return;
+        }
+        let own_sc_address = self.blockchain().get_sc_address();
+        if multi_call_data.has_step_to(&own_sc_address) {
+            self.reject_call_data(tx_id, CallDataError::InvalidTarget);
+            return;
+        }
+        let has_disallowed_step = multi_call_data
+            .calls
+            .iter()
+            .any(|step| !self.is_call_allowed(&step.to, &step.call_data.endpoint));
+        if has_disallowed_step {
+            self.reject_call_data(tx_id, CallDataError::CallNotAllowed);
+            return;
+        }
+
+        let total_gas_limit = multi_call_data.total_gas_limit();
+
+        let gas_left = self.blockchain().get_gas_left();
+        require!(
+            gas_left
+                > total_gas_limit + MULTI_CALL_GAS_OVERHEAD + DEFAULT_GAS_LIMIT_FOR_REFUND_CALLBACK,
+            "Not enough gas to execute"
+        );
+
+        let execution_gas_fee = self.get_execution_gas_fee(&payment, total_gas_limit);
+        if execution_gas_fee >= payment.amount {
+            self.reject_execution_gas_fee(tx_id, &execution_gas_fee);
+            return;
+        }
+        self.hold_executor_fees(tx_id, payment, execution_gas_fee);
+
+        let block_round = self.blockchain().get_block_round();
+        self.ongoing_execution(tx_id).set(block_round);
+        self.pending_multi_call(tx_id).set(true);
+        self.set_transaction_status(tx_id, ExecutionStatus::Executing);
+
+        self.tx()
+            .to(&own_sc_address)
+            .raw_call("executeCalls")
+            .argument(&tx_id)
+            .gas(total_gas_limit + MULTI_CALL_GAS_OVERHEAD)
+            .callback(self.callbacks().execution_callback(tx_id))
+            .with_extra_gas_for_callback(DEFAULT_GAS_LIMIT_FOR_REFUND_CALLBACK)
+            .register_promise();
+    }
+
+    /// The gas requested by the call data, at the fee estimator's price for the bridged token.
+    /// Executions triggered by MultiTransfer are not charged.
+    fn get_execution_gas_fee(&self, payment: &EsdtTokenPayment, gas_limit: u64) -> BigUint {
+        if !self.execution_gas_fee_enabled().get() {
+            return BigUint::zero();
+        }
+        if self.blockchain().get_caller() == self.multi_transfer_address().get() {
+            return BigUint::zero();
+        }
+
+        self.get_price_per_gas_unit(&payment.token_identifier) * BigUint::from(gas_limit)
+    }
+
+    /// Deducts the execution gas fee and the executor's share of the bridged amount.
+    /// The fees are held until the execution is settled: the executor is paid if it succeeded,
+    /// otherwise they are refunded along with the payment.
+    /// Executions triggered by MultiTransfer are not charged.
+    fn hold_executor_fees(
+        &self,
+        tx_id: usize,
+        mut payment: EsdtTokenPayment,
+        execution_gas_fee: BigUint,
+    ) -> EsdtTokenPayment {
+        let caller = self.blockchain().get_caller();
+        if caller == self.multi_transfer_address().get() {
+            return payment;
+        }
+
+        payment.amount -= &execution_gas_fee;
+        let fee_percentage = self.executor_fee_percentage().get();
+        let executor_fee =
+            &(&payment.amount * &BigUint::from(fee_percentage)) / &BigUint::from(PERCENTAGE_TOTAL);
+        payment.amount -= &executor_fee;
+
+        let fee = execution_gas_fee + executor_fee;
+        if fee == 0 {
+            return payment;
+        }
+
+        self.payments(tx_id).set(&payment);
+        self.executor(tx_id).set(&caller);
+        self.held_executor_fee(tx_id).set(&fee);
+
+        payment
+    }
+
+    fn pay_held_executor_fee(&self, tx_id: usize) {
+        let fee = self.held_executor_fee(tx_id).take();
+        let executor = self.executor(tx_id).take();
+        if fee == 0 {
+            return;
+        }
+
+        let payment = self.payments(tx_id).get();
+        self.tx()
+            .to(&executor)
+            .single_esdt(&payment.token_identifier, payment.token_nonce, &fee)
+            .transfer();
+
+        self.executor_fee_paid_event(tx_id, &executor, &fee);
+    }
+
+    /// The bridged amount can not cover the execution gas fee, so the transaction is bridged back.
+    fn reject_execution_gas_fee(&self, tx_id: usize, fee: &BigUint) {
+        self.execution_gas_fee_above_amount_event(tx_id, fee);
+        self.refund_transaction(tx_id, RefundReason::ExecutionGasFeeAboveAmount);
+        self.cleanup_transaction(tx_id);
+        self.set_transaction_status(tx_id, ExecutionStatus::Refunded);
+    }
+
+    fn reject_call_data(&self, tx_id: usize, error: CallDataError) {
+        self.call_data_rejected_event(tx_id, error);
+        self.finish_execute_gracefully(tx_id);
+    }
+
+    /// Executions triggered by MultiTransfer must not fail, as that would revert the whole batch,
+    /// so the payment is set aside instead of being bridged back right away.
     fn finish_execute_gracefully(&self, tx_id: usize) {
-        self.refund_transaction(tx_id);
+        let caller = self.blockchain().get_caller();
+        let status = if caller == self.multi_transfer_address().get() {
+            let tx = self.get_pending_transaction_by_id(tx_id);
+            let payment = self.payments(tx_id).get();
+            self.set_aside_payments(
+                tx_id,
+                &tx,
+                PaymentsVec::from_single_item(payment),
+                RefundReason::InvalidCallData,
+            );
+            ExecutionStatus::RefundPending
+        } else {
+            self.refund_transaction(tx_id, RefundReason::InvalidCallData);
+            ExecutionStatus::Refunded
+        };
         self.cleanup_transaction(tx_id);
+        self.set_transaction_status(tx_id, status);
+    }
+
+    fn set_transaction_status(&self, tx_id: usize, status: ExecutionStatus) {
+        let block_round = self.blockchain().get_block_round();
+        self.transaction_status(tx_id).set(status);
+        self.transaction_status_round(tx_id).set(block_round);
+
+        self.transaction_status_event(tx_id, status, block_round);
     }
 
     fn cleanup_transaction(&self, tx_id: usize) {
-        self.pending_transactions().remove(&tx_id);
+        if let Some(tx) = self.pending_transactions().remove(&tx_id) {
+            self.pending_tx_ids().swap_remove(&tx_id);
+            self.pending_tx_ids_by_recipient(&tx.to).swap_remove(&tx_id);
+            self.pending_tx_ids_by_batch_id(self.batch_id(tx_id).get())
+                .swap_remove(&tx_id);
+        }
         self.ongoing_execution(tx_id).clear();
+        self.pending_multi_call(tx_id).clear();
+        self.deposit_round(tx_id).clear();
+        self.executor(tx_id).clear();
+        self.held_executor_fee(tx_id).clear();
+    }
+
+    fn index_pending_transaction(&self, tx_id: usize, tx: &EthTransaction<Self::Api>) {
+        self.pending_tx_ids().insert(tx_id);
+        self.pending_tx_ids_by_recipient(&tx.to).insert(tx_id);
+        self.pending_tx_ids_by_batch_id(self.batch_id(tx_id).get())
+            .insert(tx_id);
     }
 
     fn get_next_tx_id(&self) -> usize {
@@ -201,6 +698,32 @@ pub trait BridgeProxyContract:
         next_tx_id
     }
 
+    /// Adds the transactions deposited before the pending transaction indexes existed
+    /// to the indexes, checking at most MAX_PENDING_TRANSACTIONS_PAGE_SIZE tx IDs per call,
+    /// starting from `start_tx_id`. Already indexed transactions are left as they are.
+    ///
+    /// Returns the tx ID to continue from, or 0 once all the tx IDs were checked.
+    #[only_owner]
+    #[endpoint(indexPendingTransactions)]
+    fn index_pending_transactions(&self, start_tx_id: usize) -> usize {
+        let highest_tx_id = self.highest_tx_id().get();
+        let end_tx_id = core::cmp::min(
+            start_tx_id.saturating_add(MAX_PENDING_TRANSACTIONS_PAGE_SIZE),
+            highest_tx_id.saturating_add(1),
+        );
+        for tx_id in start_tx_id..end_tx_id {
+            if let Some(tx) = self.pending_transactions().get(&tx_id) {
+                self.index_pending_transaction(tx_id, &tx);
+            }
+        }
+
+        if end_tx_id > highest_tx_id {
+            0
+        } else {
+            end_tx_id
+        }
+    }
+
     #[view(getPendingTransactionById)]
     fn get_pending_transaction_by_id(&self, tx_id: usize) -> EthTransaction<Self::Api> {
         let tx = self.pending_transactions().get(&tx_id);
@@ -218,4 +741,166 @@ pub trait BridgeProxyContract:
         }
         transactions
     }
+
+    #[view(getPendingTransactionsCount)]
+    fn get_pending_transactions_count(&self) -> usize {
+        self.pending_transactions().len()
+    }
+
+    /// Returns at most `limit` pending transactions, skipping the first `offset` ones.
+    /// The page size is capped at MAX_PENDING_TRANSACTIONS_PAGE_SIZE.
+    /// Transactions deposited before the indexes existed are only listed
+    /// once `indexPendingTransactions` went over them.
+    #[view(getPendingTransactionsPage)]
+    fn get_pending_transactions_page(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<MultiValue2<usize, EthTransaction<Self::Api>>> {
+        let tx_ids_mapper = self.pending_tx_ids();
+        let tx_ids = (offset.saturating_add(1)..=tx_ids_mapper.len())
+            .map(|index| tx_ids_mapper.get_by_index(index));
+        self.collect_pending_transactions(tx_ids, limit, |tx_id, tx| MultiValue2((tx_id, tx)))
+    }
+
+    /// Same as `getPendingTransactionsPage`, restricted to the transactions sent to `recipient`.
+    #[view(getPendingTransactionsByRecipient)]
+    fn get_pending_transactions_by_recipient(
+        &self,
+        recipient: ManagedAddress,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<MultiValue2<usize, EthTransaction<Self::Api>>> {
+        let tx_ids_mapper = self.pending_tx_ids_by_recipient(&recipient);
+        let tx_ids = (offset.saturating_add(1)..=tx_ids_mapper.len())
+            .map(|index| tx_ids_mapper.get_by_index(index));
+        self.collect_pending_transactions(tx_ids, limit, |tx_id, tx| MultiValue2((tx_id, tx)))
+    }
+
+    /// Same as `getPendingTransactionsPage`, restricted to the transactions
+    /// deposited from the given MultiTransfer batch.
+    #[view(getPendingTransactionsByBatchId)]
+    fn get_pending_transactions_by_batch_id(
+        &self,
+        batch_id: u64,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<MultiValue2<usize, EthTransaction<Self::Api>>> {
+        let tx_ids_mapper = self.pending_tx_ids_by_batch_id(batch_id);
+        let tx_ids = (offset.saturating_add(1)..=tx_ids_mapper.len())
+            .map(|index| tx_ids_mapper.get_by_index(index));
+        self.collect_pending_transactions(tx_ids, limit, |tx_id, tx| MultiValue2((tx_id, tx)))
+    }
+
+    /// Returns a page of pending transactions along with the payment each one will forward.
+    #[view(getPendingTransactionsWithPayments)]
+    fn get_pending_transactions_with_payments(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<MultiValue3<usize, EthTransaction<Self::Api>, EsdtTokenPayment>> {
+        let tx_ids_mapper = self.pending_tx_ids();
+        let tx_ids = (offset.saturating_add(1)..=tx_ids_mapper.len())
+            .map(|index| tx_ids_mapper.get_by_index(index));
+        self.collect_pending_transactions(tx_ids, limit, |tx_id, tx| {
+            MultiValue3((tx_id, tx, self.payments(tx_id).get()))
+        })
+    }
+
+    /// Only the transactions of the returned page are decoded.
+    fn collect_pending_transactions<T, I, M>(
+        &self,
+        tx_ids: I,
+        limit: usize,
+        map: M,
+    ) -> MultiValueEncoded<T>
+    where
+        T: TopEncodeMulti,
+        I: Iterator<Item = usize>,
+        M: Fn(usize, EthTransaction<Self::Api>) -> T,
+    {
+        let page_size = core::cmp::min(limit, MAX_PENDING_TRANSACTIONS_PAGE_SIZE);
+        let mut transactions = MultiValueEncoded::new();
+        for tx_id in tx_ids.take(page_size) {
+            if let Some(tx) = self.pending_transactions().get(&tx_id) {
+                transactions.push(map(tx_id, tx));
+            }
+        }
+        transactions
+    }
+
+    /// Returns the current status of a transaction,
+    /// along with the block round in which it was last updated.
+    #[view(getTransactionStatus)]
+    fn get_transaction_status(&self, tx_id: usize) -> MultiValue2<ExecutionStatus, u64> {
+        let status = self.transaction_status(tx_id).get();
+        let block_round = self.transaction_status_round(tx_id).get();
+        (status, block_round).into()
+    }
+
+    #[event("transactionStatus")]
+    fn transaction_status_event(
+        &self,
+        #[indexed] tx_id: usize,
+        #[indexed] status: ExecutionStatus,
+        #[indexed] block_round: u64,
+    );
+
+    #[event("callDataRejected")]
+    fn call_data_rejected_event(&self, #[indexed] tx_id: usize, #[indexed] error: CallDataError);
+
+    #[event("executionResult")]
+    fn execution_result_event(&self, #[indexed] tx_id: usize, results: ManagedVec<ManagedBuffer>);
+
+    #[event("executionFailed")]
+    fn execution_failed_event(
+        &self,
+        #[indexed] tx_id: usize,
+        #[indexed] err_code: u32,
+        err_msg: &ManagedBuffer,
+    );
+
+    #[event("backTransferForwarded")]
+    fn back_transfer_forwarded_event(
+        &self,
+        #[indexed] tx_id: usize,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("paymentsSetAside")]
+    fn payments_set_aside_event(&self, #[indexed] tx_id: usize, payments: &PaymentsVec<Self::Api>);
+
+    #[event("transactionSettled")]
+    fn transaction_settled_event(&self, #[indexed] tx_id: usize);
+
+    #[event("cancelTransaction")]
+    fn cancel_transaction_event(
+        &self,
+        #[indexed] tx_id: usize,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] refunded: bool,
+    );
+
+    #[event("stuckExecutionRecovered")]
+    fn stuck_execution_recovered_event(&self, #[indexed] tx_id: usize, #[indexed] refunded: bool);
+
+    #[event("lateExecutionCallback")]
+    fn late_execution_callback_event(&self, #[indexed] tx_id: usize);
+
+    #[event("executorFeePaid")]
+    fn executor_fee_paid_event(
+        &self,
+        #[indexed] tx_id: usize,
+        #[indexed] executor: &ManagedAddress,
+        #[indexed] fee: &BigUint,
+    );
+
+    #[event("executionGasFeeAboveAmount")]
+    fn execution_gas_fee_above_amount_event(
+        &self,
+        #[indexed] tx_id: usize,
+        #[indexed] fee: &BigUint,
+    );
 }
@@ -92,7 +92,7 @@ where
         self,
         eth_tx: Arg0,
         batch_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, (), Gas, usize> {
         self.wrapped_tx
             .raw_call("deposit")
             .argument(&eth_tx)
@@ -113,6 +113,99 @@ where
             .original_result()
     }
 
+    /// Cancels a pending transaction, either delivering the payment to the recipient 
+    /// or refunding it to Ethereum. 
+    /// Can only be called by the owner or the recipient, after a delay counted from the deposit. 
+    /// Transactions being executed can not be cancelled, as the payment was already sent along with the call, 
+    /// see `recoverStuckExecution`. 
+    pub fn cancel<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        tx_id: Arg0,
+        refund_to_ethereum: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel")
+            .argument(&tx_id)
+            .argument(&refund_to_ethereum)
+            .original_result()
+    }
+
+    /// Recovers a transaction whose execution callback never completed, leaving it stuck as executing, 
+    /// either delivering the payment to the recipient or refunding it to Ethereum. 
+    /// The executor is not paid. A callback arriving afterwards is ignored. 
+    ///  
+    /// The payment is only back in the contract if the call itself failed, 
+    /// so the owner must check the outcome of the call before recovering it. 
+    pub fn recover_stuck_execution<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        tx_id: Arg0,
+        refund_to_ethereum: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recoverStuckExecution")
+            .argument(&tx_id)
+            .argument(&refund_to_ethereum)
+            .original_result()
+    }
+
+    /// Executes the steps of a multi-call, forwarding the tokens sent back by each step to the next one. 
+    /// Only callable by the contract itself, through `execute`, so a failing step reverts the whole sequence. 
+    /// The steps run at most once per execution. 
+    pub fn execute_calls<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeCalls")
+            .argument(&tx_id)
+            .original_result()
+    }
+
+    /// Bridges back to Ethereum the tokens set aside after an execution, 
+    /// i.e. the payment of a failed call or the tokens sent back by a successful one. 
+    /// Can be called by anyone, and retried until the EsdtSafe accepts the tokens. 
+    pub fn settle_transaction<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("settleTransaction")
+            .argument(&tx_id)
+            .original_result()
+    }
+
+    /// Adds the transactions deposited before the pending transaction indexes existed 
+    /// to the indexes, checking at most MAX_PENDING_TRANSACTIONS_PAGE_SIZE tx IDs per call, 
+    /// starting from `start_tx_id`. Already indexed transactions are left as they are. 
+    ///  
+    /// Returns the tx ID to continue from, or 0 once all the tx IDs were checked. 
+    pub fn index_pending_transactions<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        start_tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("indexPendingTransactions")
+            .argument(&start_tx_id)
+            .original_result()
+    }
+
     pub fn get_pending_transaction_by_id<
         Arg0: ProxyArg<usize>,
     >(
@@ -135,6 +228,108 @@ where
             .original_result()
     }
 
+    pub fn get_pending_transactions_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingTransactionsCount")
+            .original_result()
+    }
+
+    /// Returns at most `limit` pending transactions, skipping the first `offset` ones. 
+    /// The page size is capped at MAX_PENDING_TRANSACTIONS_PAGE_SIZE. 
+    /// Transactions deposited before the indexes existed are only listed 
+    /// once `indexPendingTransactions` went over them. 
+    pub fn get_pending_transactions_page<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        offset: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<usize, transaction::EthTransaction<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingTransactionsPage")
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// Same as `getPendingTransactionsPage`, restricted to the transactions sent to `recipient`. 
+    pub fn get_pending_transactions_by_recipient<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        recipient: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<usize, transaction::EthTransaction<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingTransactionsByRecipient")
+            .argument(&recipient)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// Same as `getPendingTransactionsPage`, restricted to the transactions 
+    /// deposited from the given MultiTransfer batch. 
+    pub fn get_pending_transactions_by_batch_id<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        batch_id: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<usize, transaction::EthTransaction<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingTransactionsByBatchId")
+            .argument(&batch_id)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// Returns a page of pending transactions along with the payment each one will forward. 
+    pub fn get_pending_transactions_with_payments<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        offset: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<usize, transaction::EthTransaction<Env::Api>, EsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingTransactionsWithPayments")
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// Returns the current status of a transaction, 
+    /// along with the block round in which it was last updated. 
+    pub fn get_transaction_status<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ExecutionStatus, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTransactionStatus")
+            .argument(&tx_id)
+            .original_result()
+    }
+
     pub fn set_multi_transfer_contract_address<
         Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
@@ -174,6 +369,54 @@ where
             .original_result()
     }
 
+    /// Share of the bridged amount paid to whoever executes a queued transaction. 
+    /// Expressed relative to PERCENTAGE_TOTAL, i.e. 100 is 1%. 
+    pub fn set_executor_fee_percentage<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutorFeePercentage")
+            .argument(&percentage)
+            .original_result()
+    }
+
+    /// Bounds for the gas limit requested by Ethereum -> MultiversX smart contract calls. 
+    /// Calls outside of these bounds are refunded. 
+    pub fn set_sc_call_gas_limits<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        min_gas_limit: Arg0,
+        max_gas_limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setScCallGasLimits")
+            .argument(&min_gas_limit)
+            .argument(&max_gas_limit)
+            .original_result()
+    }
+
+    /// When enabled, executions not triggered by MultiTransfer pay for the requested gas, 
+    /// priced through the fee estimator, out of the bridged amount. 
+    pub fn set_execution_gas_fee_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutionGasFeeEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
     pub fn multi_transfer_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -201,6 +444,56 @@ where
             .original_result()
     }
 
+    /// Tokens waiting to be bridged back to Ethereum, through `settleTransaction`. 
+    pub fn unsettled_payments<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnsettledPayments")
+            .argument(&tx_id)
+            .original_result()
+    }
+
+    pub fn executor_fee_percentage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutorFeePercentage")
+            .original_result()
+    }
+
+    pub fn execution_gas_fee_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isExecutionGasFeeEnabled")
+            .original_result()
+    }
+
+    pub fn min_gas_limit_for_sc_call(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinGasLimitForScCall")
+            .original_result()
+    }
+
+    pub fn max_gas_limit_for_sc_call(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxGasLimitForScCall")
+            .original_result()
+    }
+
     pub fn highest_tx_id(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
@@ -210,6 +503,190 @@ where
             .original_result()
     }
 
+    /// When enabled, only allowlisted targets can be called by the proxy. 
+    /// Otherwise, any contract and endpoint can be called. 
+    pub fn set_allowlist_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAllowlistEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Allows calls to `target`. 
+    /// If no endpoints are given, any endpoint of the target can be called. 
+    /// Otherwise, only the given endpoints, adding to the ones previously allowed. 
+    pub fn add_allowed_target<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        target: Arg0,
+        endpoints: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAllowedTarget")
+            .argument(&target)
+            .argument(&endpoints)
+            .original_result()
+    }
+
+    pub fn remove_allowed_target<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        target: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAllowedTarget")
+            .argument(&target)
+            .original_result()
+    }
+
+    pub fn allowlist_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAllowlistEnabled")
+            .original_result()
+    }
+
+    pub fn allowed_targets(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowedTargets")
+            .original_result()
+    }
+
+    pub fn allowed_endpoints<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        target: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowedEndpoints")
+            .argument(&target)
+            .original_result()
+    }
+
+    pub fn set_fee_estimator_contract_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFeeEstimatorContractAddress")
+            .argument(&new_address)
+            .original_result()
+    }
+
+    pub fn set_eth_tx_gas_limit<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_limit: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEthTxGasLimit")
+            .argument(&new_limit)
+            .original_result()
+    }
+
+    /// Default price being used if the aggregator lacks a mapping for this token 
+    /// or the aggregator address is not set 
+    pub fn set_default_price_per_gas_unit<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        default_price_per_gas_unit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDefaultPricePerGasUnit")
+            .argument(&token_id)
+            .argument(&default_price_per_gas_unit)
+            .original_result()
+    }
+
+    /// Token ticker being used when querying the aggregator for GWEI prices 
+    pub fn set_token_ticker<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenTicker")
+            .argument(&token_id)
+            .argument(&ticker)
+            .original_result()
+    }
+
+    /// Returns the fee for the given token ID (the fee amount is in the given token) 
+    pub fn calculate_required_fee<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("calculateRequiredFee")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn fee_estimator_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeEstimatorContractAddress")
+            .original_result()
+    }
+
+    pub fn default_price_per_gas_unit<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDefaultPricePerGasUnit")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn eth_tx_gas_limit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEthTxGasLimit")
+            .original_result()
+    }
+
     pub fn pause_endpoint(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -237,3 +714,15 @@ where
             .original_result()
     }
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq)]
+pub enum ExecutionStatus {
+    None,
+    Pending,
+    Executing,
+    Succeeded,
+    Refunded,
+    Cancelled,
+    RefundPending,
+}
@@ -0,0 +1,69 @@
+use multiversx_sc::imports::*;
+
+#[multiversx_sc::module]
+pub trait CallAllowlistModule {
+    /// When enabled, only allowlisted targets can be called by the proxy.
+    /// Otherwise, any contract and endpoint can be called.
+    #[only_owner]
+    #[endpoint(setAllowlistEnabled)]
+    fn set_allowlist_enabled(&self, enabled: bool) {
+        self.allowlist_enabled().set(enabled);
+    }
+
+    /// Allows calls to `target`.
+    /// If no endpoints are given, any endpoint of the target can be called.
+    /// Otherwise, only the given endpoints, adding to the ones previously allowed.
+    #[only_owner]
+    #[endpoint(addAllowedTarget)]
+    fn add_allowed_target(
+        &self,
+        target: ManagedAddress,
+        endpoints: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(
+            self.blockchain().is_smart_contract(&target),
+            "Target must be a smart contract"
+        );
+
+        self.allowed_targets().insert(target.clone());
+        let mut allowed_endpoints_mapper = self.allowed_endpoints(&target);
+        for endpoint in endpoints {
+            require!(!endpoint.is_empty(), "Invalid endpoint name");
+            allowed_endpoints_mapper.insert(endpoint);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeAllowedTarget)]
+    fn remove_allowed_target(&self, target: ManagedAddress) {
+        require!(
+            self.allowed_targets().swap_remove(&target),
+            "Target is not allowed"
+        );
+        self.allowed_endpoints(&target).clear();
+    }
+
+    fn is_call_allowed(&self, target: &ManagedAddress, endpoint: &ManagedBuffer) -> bool {
+        if !self.allowlist_enabled().get() {
+            return true;
+        }
+        if !self.allowed_targets().contains(target) {
+            return false;
+        }
+
+        let allowed_endpoints_mapper = self.allowed_endpoints(target);
+        allowed_endpoints_mapper.is_empty() || allowed_endpoints_mapper.contains(endpoint)
+    }
+
+    #[view(isAllowlistEnabled)]
+    #[storage_mapper("allowlistEnabled")]
+    fn allowlist_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getAllowedTargets)]
+    #[storage_mapper("allowedTargets")]
+    fn allowed_targets(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getAllowedEndpoints)]
+    #[storage_mapper("allowedEndpoints")]
+    fn allowed_endpoints(&self, target: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+}
@@ -1,6 +1,14 @@
 use multiversx_sc::imports::*;
 
-use transaction::EthTransaction;
+use token_module::PERCENTAGE_TOTAL;
+use transaction::{refund_reason::RefundReason, EthTransaction, PaymentsVec};
+
+use crate::tx_status::ExecutionStatus;
+
+const MAX_EXECUTOR_FEE_PERCENTAGE: u32 = PERCENTAGE_TOTAL / 100; // 1%
+
+pub const MIN_GAS_LIMIT_FOR_SC_CALL_STORAGE_KEY: &[u8] = b"minGasLimitForScCall";
+pub const MAX_GAS_LIMIT_FOR_SC_CALL_STORAGE_KEY: &[u8] = b"maxGasLimitForScCall";
 
 #[multiversx_sc::module]
 pub trait ConfigModule {
@@ -55,6 +63,36 @@ pub trait ConfigModule {
         }
     }
 
+    /// Share of the bridged amount paid to whoever executes a queued transaction.
+    /// Expressed relative to PERCENTAGE_TOTAL, i.e. 100 is 1%.
+    #[only_owner]
+    #[endpoint(setExecutorFeePercentage)]
+    fn set_executor_fee_percentage(&self, percentage: u32) {
+        require!(
+            percentage <= MAX_EXECUTOR_FEE_PERCENTAGE,
+            "Executor fee percentage too high"
+        );
+        self.executor_fee_percentage().set(percentage);
+    }
+
+    /// Bounds for the gas limit requested by Ethereum -> MultiversX smart contract calls.
+    /// Calls outside of these bounds are refunded.
+    #[only_owner]
+    #[endpoint(setScCallGasLimits)]
+    fn set_sc_call_gas_limits(&self, min_gas_limit: u64, max_gas_limit: u64) {
+        require!(min_gas_limit <= max_gas_limit, "Invalid gas limits");
+        self.min_gas_limit_for_sc_call().set(min_gas_limit);
+        self.max_gas_limit_for_sc_call().set(max_gas_limit);
+    }
+
+    /// When enabled, executions not triggered by MultiTransfer pay for the requested gas,
+    /// priced through the fee estimator, out of the bridged amount.
+    #[only_owner]
+    #[endpoint(setExecutionGasFeeEnabled)]
+    fn set_execution_gas_fee_enabled(&self, enabled: bool) {
+        self.execution_gas_fee_enabled().set(enabled);
+    }
+
     #[view(getMultiTransferAddress)]
     #[storage_mapper("multiTransferAddress")]
     fn multi_transfer_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -73,13 +111,76 @@ pub trait ConfigModule {
     #[storage_mapper("payments")]
     fn payments(&self, tx_id: usize) -> SingleValueMapper<EsdtTokenPayment<Self::Api>>;
 
+    #[storage_mapper("unsettledTransactions")]
+    fn unsettled_transactions(&self) -> MapMapper<usize, EthTransaction<Self::Api>>;
+
+    /// Tokens waiting to be bridged back to Ethereum, through `settleTransaction`.
+    #[view(getUnsettledPayments)]
+    #[storage_mapper("unsettledPayments")]
+    fn unsettled_payments(&self, tx_id: usize) -> SingleValueMapper<PaymentsVec<Self::Api>>;
+
+    #[storage_mapper("unsettledRefundReason")]
+    fn unsettled_refund_reason(&self, tx_id: usize) -> SingleValueMapper<RefundReason>;
+
     #[storage_mapper("batch_id")]
     fn batch_id(&self, tx_id: usize) -> SingleValueMapper<u64>;
 
+    /// Indexes of the pending transactions, so they can be paged without decoding every entry.
+    #[storage_mapper("pendingTxIds")]
+    fn pending_tx_ids(&self) -> UnorderedSetMapper<usize>;
+
+    #[storage_mapper("pendingTxIdsByRecipient")]
+    fn pending_tx_ids_by_recipient(&self, recipient: &ManagedAddress) -> UnorderedSetMapper<usize>;
+
+    #[storage_mapper("pendingTxIdsByBatchId")]
+    fn pending_tx_ids_by_batch_id(&self, batch_id: u64) -> UnorderedSetMapper<usize>;
+
+    #[view(getExecutorFeePercentage)]
+    #[storage_mapper("executorFeePercentage")]
+    fn executor_fee_percentage(&self) -> SingleValueMapper<u32>;
+
+    #[view(isExecutionGasFeeEnabled)]
+    #[storage_mapper("executionGasFeeEnabled")]
+    fn execution_gas_fee_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getMinGasLimitForScCall)]
+    #[storage_mapper("minGasLimitForScCall")]
+    fn min_gas_limit_for_sc_call(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxGasLimitForScCall)]
+    #[storage_mapper("maxGasLimitForScCall")]
+    fn max_gas_limit_for_sc_call(&self) -> SingleValueMapper<u64>;
+
     #[view(highestTxId)]
     #[storage_mapper("highest_tx_id")]
     fn highest_tx_id(&self) -> SingleValueMapper<usize>;
 
+    #[storage_mapper("depositRound")]
+    fn deposit_round(&self, tx_id: usize) -> SingleValueMapper<u64>;
+
+    /// The round of the upgrade that started recording deposit rounds,
+    /// used for the transactions deposited before it.
+    #[storage_mapper("legacyDepositRound")]
+    fn legacy_deposit_round(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("transactionStatus")]
+    fn transaction_status(&self, tx_id: usize) -> SingleValueMapper<ExecutionStatus>;
+
+    #[storage_mapper("transactionStatusRound")]
+    fn transaction_status_round(&self, tx_id: usize) -> SingleValueMapper<u64>;
+
     #[storage_mapper("ongoingExecution")]
     fn ongoing_execution(&self, tx_id: usize) -> SingleValueMapper<u64>;
+
+    /// Set while the `executeCalls` promise of a multi-call is in flight,
+    /// so the steps can only be run once per execution.
+    #[storage_mapper("pendingMultiCall")]
+    fn pending_multi_call(&self, tx_id: usize) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("executor")]
+    fn executor(&self, tx_id: usize) -> SingleValueMapper<ManagedAddress>;
+
+    /// Fees owed to the executor, paid once the execution succeeded.
+    #[storage_mapper("heldExecutorFee")]
+    fn held_executor_fee(&self, tx_id: usize) -> SingleValueMapper<BigUint>;
 }
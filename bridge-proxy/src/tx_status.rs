@@ -0,0 +1,15 @@
+use multiversx_sc::derive_imports::*;
+
+/// Lifecycle of a transaction deposited in the bridge proxy.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq)]
+pub enum ExecutionStatus {
+    None,
+    Pending,
+    Executing,
+    Succeeded,
+    Refunded,
+    Cancelled,
+    /// The payment was set aside, it is refunded once `settleTransaction` is called.
+    RefundPending,
+}
@@ -3,21 +3,23 @@
 use std::collections::LinkedList;
 use std::ops::Add;
 
+use bridge_proxy::bridge_proxy_contract_proxy::ExecutionStatus;
 use bridge_proxy::{bridge_proxy_contract_proxy, config::ProxyTrait as _};
-use bridge_proxy::{bridged_tokens_wrapper_proxy, ProxyTrait};
+use bridge_proxy::{bridged_tokens_wrapper_proxy, esdt_safe_proxy, ProxyTrait};
+use bridge_proxy::{config::ConfigModule, tx_status};
 
 use crowdfunding_esdt::crowdfunding_esdt_proxy;
 use multiversx_sc::codec::NestedEncode;
 use multiversx_sc::contract_base::ManagedSerializer;
 use multiversx_sc::sc_print;
 use multiversx_sc::types::{
-    EgldOrEsdtTokenIdentifier, EsdtTokenPayment, ManagedOption, ReturnsNewAddress, TestAddress,
-    TestSCAddress, TestTokenIdentifier,
+    EgldOrEsdtTokenIdentifier, EsdtTokenPayment, ManagedOption, MultiValueEncoded,
+    ReturnsNewAddress, ReturnsResult, TestAddress, TestSCAddress, TestTokenIdentifier,
 };
 use multiversx_sc::{
     api::{HandleConstraints, ManagedTypeApi},
     codec::{
-        multi_types::{MultiValueVec, OptionalValue},
+        multi_types::{MultiValue2, MultiValueVec, OptionalValue},
         TopEncodeMultiOutput,
     },
     storage::mappers::SingleValue,
@@ -32,12 +34,13 @@ use multiversx_sc_scenario::{
     rust_biguint,
     scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
     scenario_model::*,
-    ContractInfo, ScenarioWorld,
+    ContractInfo, ScenarioWorld, WhiteboxContract,
 };
-use multiversx_sc_scenario::{ExpectValue, ScenarioTxRun};
+use multiversx_sc_scenario::{ExpectError, ExpectValue, ScenarioTxRun};
 
 use eth_address::*;
-use transaction::{CallData, EthTransaction};
+use transaction::multi_call_data::{CallStep, MultiCallData};
+use transaction::{refund_reason::RefundReason, CallData, EthTransaction};
 
 const BRIDGE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("BRIDGE-123456");
 const WBRIDGE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("WBRIDGE-123456");
@@ -46,6 +49,7 @@ const GAS_LIMIT: u64 = 10_000_000;
 const CF_DEADLINE: u64 = 7 * 24 * 60 * 60; // 1 week in seconds
 
 const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
 const BRIDGE_PROXY_ADDRESS: TestSCAddress = TestSCAddress::new("bridge-proxy");
 const CROWDFUNDING_ADDRESS: TestSCAddress = TestSCAddress::new("crowfunding");
 const MULTI_TRANSFER_ADDRESS: TestSCAddress = TestSCAddress::new("multi-transfer");
@@ -58,6 +62,7 @@ const CROWDFUNDING_PATH_EXPR: MxscPath =
 const MULTI_TRANSFER_PATH_EXPR: &str =
     "mxsc:../multi-transfer-esdt/output/multi-transfer-esdt.mxsc.json";
 const ESDT_SAFE_PATH_EXPR: &str = "mxsc:../esdt-safe/output/esdt-safe.mxsc.json";
+const ESDT_SAFE_CODE_PATH: MxscPath = MxscPath::new("../esdt-safe/output/esdt-safe.mxsc.json");
 const BRIDGED_TOKENS_WRAPPER_CODE_PATH_EXPR: MxscPath =
     MxscPath::new("../bridged-tokens-wrapper/output/bridged-tokens-wrapper.mxsc.json");
 
@@ -93,12 +98,15 @@ impl BridgeProxyTestState {
             .account(OWNER_ADDRESS)
             .nonce(1)
             .esdt_balance(TokenIdentifier::from(BRIDGE_TOKEN_ID), 10_000u64)
+            .account(USER_ADDRESS)
+            .nonce(1)
             .account(MULTI_TRANSFER_ADDRESS)
             .esdt_balance(TokenIdentifier::from(WBRIDGE_TOKEN_ID), 10_000u64)
             .esdt_balance(TokenIdentifier::from(BRIDGE_TOKEN_ID), 10_000u64)
             .code(multi_transfer_code)
             .account(ESDT_SAFE_ADDRESS)
-            .code(esdt_safe_code);
+            .code(esdt_safe_code)
+            .owner(OWNER_ADDRESS);
 
         let roles = vec![
             "ESDTRoleLocalMint".to_string(),
@@ -216,6 +224,85 @@ impl BridgeProxyTestState {
 
         self
     }
+
+    fn esdt_safe_deploy(&mut self) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .upgrade(
+                ManagedAddress::zero(),
+                MULTI_TRANSFER_ADDRESS.to_address(),
+                BRIDGE_PROXY_ADDRESS.to_address(),
+                150_000u64,
+            )
+            .code(ESDT_SAFE_CODE_PATH)
+            .run();
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .add_token_to_whitelist(
+                BRIDGE_TOKEN_ID,
+                "BRIDGE",
+                false,
+                true,
+                BigUint::zero(),
+                BigUint::zero(),
+                BigUint::zero(),
+                OptionalValue::<BigUint<StaticApi>>::None,
+            )
+            .run();
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .unpause_endpoint()
+            .run();
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(BRIDGE_PROXY_ADDRESS)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_esdt_safe_contract_address(OptionalValue::Some(ESDT_SAFE_ADDRESS))
+            .run();
+
+        self
+    }
+
+    fn deposit_to_user(&mut self) -> &mut Self {
+        let eth_tx = EthTransaction {
+            from: EthAddress {
+                raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+            },
+            to: ManagedAddress::from(USER_ADDRESS.eval_to_array()),
+            token_id: BRIDGE_TOKEN_ID.into(),
+            amount: BigUint::from(500u64),
+            tx_nonce: 1u64,
+            call_data: ManagedOption::none(),
+        };
+
+        self.world
+            .tx()
+            .from(MULTI_TRANSFER_ADDRESS)
+            .to(BRIDGE_PROXY_ADDRESS)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .deposit(&eth_tx, 1u64)
+            .single_esdt(
+                &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+                0u64,
+                &BigUint::from(500u64),
+            )
+            .run();
+
+        self
+    }
 }
 
 #[test]
@@ -391,6 +478,28 @@ fn multiple_deposit_test() {
         .returns(ExpectValue(500u64))
         .run();
 
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Succeeded,
+            0u64,
+        ))))
+        .run();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(2u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Pending,
+            0u64,
+        ))))
+        .run();
+
     test.world
         .tx()
         .from(OWNER_ADDRESS)
@@ -417,6 +526,151 @@ fn multiple_deposit_test() {
         .run();
 }
 
+#[test]
+fn bridge_proxy_pending_transactions_pagination_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.deploy_crowdfunding();
+    test.config_bridge();
+
+    test.deposit_to_user();
+    test.deposit_to_user();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(300u64),
+        tx_nonce: 3u64,
+        call_data: ManagedOption::none(),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 2u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(300u64),
+        )
+        .run();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_count()
+        .returns(ExpectValue(3usize))
+        .run();
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_page(1usize, 1usize)
+        .returns(ReturnsResult)
+        .run();
+    let tx_ids: Vec<usize> = page.into_iter().map(|entry| entry.into_tuple().0).collect();
+    assert_eq!(tx_ids, vec![2]);
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_by_recipient(USER_ADDRESS.to_address(), 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    let tx_ids: Vec<usize> = page.into_iter().map(|entry| entry.into_tuple().0).collect();
+    assert_eq!(tx_ids, vec![1, 2]);
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_by_batch_id(2u64, 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    let tx_ids: Vec<usize> = page.into_iter().map(|entry| entry.into_tuple().0).collect();
+    assert_eq!(tx_ids, vec![3]);
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_with_payments(2usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    let mut entries = page.into_iter();
+    let (tx_id, tx, payment) = entries.next().unwrap().into_tuple();
+    assert!(entries.next().is_none());
+    assert_eq!(tx_id, 3);
+    assert_eq!(tx.tx_nonce, eth_tx.tx_nonce);
+    assert_eq!(payment.amount, BigUint::from(300u64));
+
+    test.world.current_block().block_round(301u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .run();
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_by_recipient(USER_ADDRESS.to_address(), 0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    let tx_ids: Vec<usize> = page.into_iter().map(|entry| entry.into_tuple().0).collect();
+    assert_eq!(tx_ids, vec![2]);
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_by_recipient(USER_ADDRESS.to_address(), 1usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    assert!(page.into_iter().next().is_none());
+
+    // indexing again does not list a transaction twice
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .index_pending_transactions(1usize)
+        .returns(ExpectValue(0usize))
+        .run();
+
+    let page = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_pending_transactions_page(0usize, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    let mut tx_ids: Vec<usize> = page.into_iter().map(|entry| entry.into_tuple().0).collect();
+    tx_ids.sort();
+    assert_eq!(tx_ids, vec![2, 3]);
+}
+
 #[test]
 fn test_highest_tx_id() {
     let mut test = BridgeProxyTestState::new();
@@ -495,3 +749,1069 @@ fn test_highest_tx_id() {
             .run();
     }
 }
+
+#[test]
+fn bridge_proxy_cancel_transaction_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.deposit_to_user();
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .returns(ExpectError(
+            4,
+            "Only owner or recipient can cancel the transaction",
+        ))
+        .run();
+
+    test.world.current_block().block_round(300u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .returns(ExpectError(4, "Transaction can't be cancelled yet"))
+        .run();
+
+    test.world.current_block().block_round(301u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Cancelled,
+            301u64,
+        ))))
+        .run();
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .returns(ExpectError(4, "Invalid tx id"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .returns(ExpectError(4, "Invalid tx id"))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_owner_cancel_transaction_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.deposit_to_user();
+
+    test.world.current_block().block_round(301u64);
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, true)
+        .returns(ExpectError(4, "Invalid tx id"))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_cancel_refund_to_ethereum_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.esdt_safe_deploy();
+    test.deposit_to_user();
+
+    test.world.current_block().block_round(301u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, true)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::zero());
+    test.world
+        .check_account(ESDT_SAFE_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    let refund_batch = test
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .get_first_batch_any_status_versioned()
+        .returns(ReturnsResult)
+        .run();
+    let (_, _, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    let refund_txs = refund_txs.to_vec();
+    assert_eq!(refund_txs.len(), 1);
+
+    let refund_tx = refund_txs.get(0);
+    assert!(refund_tx.is_refund_tx);
+    assert!(refund_tx.refund_reason == RefundReason::ProxyTransactionCancelled);
+    assert_eq!(refund_tx.amount, BigUint::from(500u64));
+    assert_eq!(
+        &refund_tx.from,
+        ManagedAddress::from(USER_ADDRESS.eval_to_array()).as_managed_buffer()
+    );
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Cancelled,
+            301u64,
+        ))))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_cancel_executing_transaction_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.deposit_to_user();
+
+    // a call in flight, the payment was sent along with it
+    let bridge_proxy_whitebox =
+        WhiteboxContract::new("sc:bridge-proxy", bridge_proxy::contract_obj);
+    test.world.whitebox_call(
+        &bridge_proxy_whitebox,
+        ScCallStep::new().from("address:owner"),
+        |sc| {
+            sc.transaction_status(1)
+                .set(tx_status::ExecutionStatus::Executing);
+            sc.ongoing_execution(1).set(900u64);
+        },
+    );
+
+    test.world.current_block().block_round(1_000u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .returns(ExpectError(4, "Transaction is being executed"))
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::zero());
+
+    // the callback never completed, the call failed and the payment is back in the contract
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .recover_stuck_execution(1u32, false)
+        .returns(ExpectError(4, "Execution can't be recovered yet"))
+        .run();
+
+    test.world.current_block().block_round(1_201u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .recover_stuck_execution(1u32, false)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .recover_stuck_execution(1u32, false)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Cancelled,
+            1_201u64,
+        ))))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_cancel_legacy_transaction_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.deposit_to_user();
+
+    // deposited before the deposit round was recorded, counted from the upgrade
+    let bridge_proxy_whitebox =
+        WhiteboxContract::new("sc:bridge-proxy", bridge_proxy::contract_obj);
+    test.world.whitebox_call(
+        &bridge_proxy_whitebox,
+        ScCallStep::new().from("address:owner"),
+        |sc| {
+            sc.deposit_round(1).clear();
+            sc.legacy_deposit_round().set(500u64);
+        },
+    );
+
+    test.world.current_block().block_round(700u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .returns(ExpectError(4, "Transaction can't be cancelled yet"))
+        .run();
+
+    test.world.current_block().block_round(801u64);
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .cancel(1u32, false)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+}
+
+#[test]
+fn bridge_proxy_executor_fee_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.deploy_crowdfunding();
+    test.config_bridge();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_executor_fee_percentage(101u32)
+        .returns(ExpectError(4, "Executor fee percentage too high"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_executor_fee_percentage(100u32)
+        .run();
+
+    let call_data: CallData<StaticApi> = CallData {
+        endpoint: ManagedBuffer::from(b"fund"),
+        gas_limit: GAS_LIMIT,
+        args: ManagedOption::some(ManagedVec::new()),
+    };
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(500u64),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(5u64));
+
+    test.world
+        .query()
+        .to(CROWDFUNDING_ADDRESS)
+        .typed(crowdfunding_esdt_proxy::CrowdfundingProxy)
+        .get_current_funds()
+        .returns(ExpectValue(495u64))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_execution_gas_fee_test() {
+    let mut test = BridgeProxyTestState::new();
+    let amount = 10_000_500u64;
+
+    test.bridge_proxy_deploy();
+    test.deploy_crowdfunding();
+    test.config_bridge();
+    test.esdt_safe_deploy();
+
+    test.world
+        .set_esdt_balance(MULTI_TRANSFER_ADDRESS, b"BRIDGE-123456", amount * 2);
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_execution_gas_fee_enabled(true)
+        .run();
+
+    // the requested gas would cost more than the bridged amount
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_default_price_per_gas_unit(BRIDGE_TOKEN_ID, 2u64)
+        .run();
+
+    let call_data: CallData<StaticApi> = CallData {
+        endpoint: ManagedBuffer::from(b"fund"),
+        gas_limit: GAS_LIMIT,
+        args: ManagedOption::some(ManagedVec::new()),
+    };
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let mut eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(amount),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data.clone()),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(amount),
+        )
+        .run();
+
+    eth_tx.tx_nonce = 2u64;
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(amount),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    // bridged back to Ethereum, without paying the executor
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::zero());
+    test.world
+        .check_account(ESDT_SAFE_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(amount));
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Refunded,
+            0u64,
+        ))))
+        .run();
+
+    let refund_batch = test
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .get_first_batch_any_status_versioned()
+        .returns(ReturnsResult)
+        .run();
+    let (_, _, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    let refund_tx = refund_txs.to_vec().get(0);
+    assert!(refund_tx.refund_reason == RefundReason::ExecutionGasFeeAboveAmount);
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_default_price_per_gas_unit(BRIDGE_TOKEN_ID, 1u64)
+        .run();
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(2u32)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(GAS_LIMIT));
+
+    test.world
+        .query()
+        .to(CROWDFUNDING_ADDRESS)
+        .typed(crowdfunding_esdt_proxy::CrowdfundingProxy)
+        .get_current_funds()
+        .returns(ExpectValue(500u64))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_execute_multi_call_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.deploy_crowdfunding();
+    test.config_bridge();
+
+    let mut calls = ManagedVec::new();
+    calls.push(CallStep {
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from(b"fund"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::none(),
+        },
+    });
+    calls.push(CallStep {
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from(b"getCurrentFunds"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::none(),
+        },
+    });
+    let call_data: MultiCallData<StaticApi> = MultiCallData::new(calls);
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(500u64),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute_calls(1u32)
+        .returns(ExpectError(4, "Only the contract itself can execute calls"))
+        .run();
+
+    test.world
+        .tx()
+        .from(BRIDGE_PROXY_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute_calls(1u32)
+        .returns(ExpectError(4, "No calls to execute"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    test.world
+        .query()
+        .to(CROWDFUNDING_ADDRESS)
+        .typed(crowdfunding_esdt_proxy::CrowdfundingProxy)
+        .get_current_funds()
+        .returns(ExpectValue(500u64))
+        .run();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Succeeded,
+            0u64,
+        ))))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_multi_call_back_transfers_fallback_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+
+    let mut calls = ManagedVec::new();
+    calls.push(CallStep {
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from(b"wrapTokens"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::none(),
+        },
+    });
+    let call_data: MultiCallData<StaticApi> = MultiCallData::new(calls)
+        .with_fallback_address(ManagedAddress::from(USER_ADDRESS.eval_to_array()));
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(500u64),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    test.world
+        .check_account(USER_ADDRESS)
+        .esdt_balance(WBRIDGE_TOKEN_ID, BigUint::from(500u64));
+}
+
+#[test]
+fn bridge_proxy_multi_call_back_transfers_settle_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.esdt_safe_deploy();
+
+    let mut unwrap_args = ManagedVec::new();
+    unwrap_args.push(ManagedBuffer::from(b"BRIDGE-123456"));
+
+    let mut calls = ManagedVec::new();
+    calls.push(CallStep {
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from(b"wrapTokens"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::none(),
+        },
+    });
+    calls.push(CallStep {
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from(b"unwrapToken"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::some(unwrap_args),
+        },
+    });
+    let call_data: MultiCallData<StaticApi> = MultiCallData::new(calls);
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(500u64),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .pause_endpoint()
+        .run();
+
+    // the tokens sent back are kept, even though the EsdtSafe can't take them
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            ExecutionStatus::Succeeded,
+            0u64,
+        ))))
+        .run();
+
+    let unsettled_payments = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .unsettled_payments(1u32)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(unsettled_payments.len(), 1);
+    assert_eq!(
+        unsettled_payments.get(0).token_identifier,
+        TokenIdentifier::from(BRIDGE_TOKEN_ID)
+    );
+    assert_eq!(unsettled_payments.get(0).amount, BigUint::from(500u64));
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .settle_transaction(1u32)
+        .returns(ExpectError(4, "Cannot create transaction while paused"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .unpause_endpoint()
+        .run();
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .settle_transaction(1u32)
+        .run();
+
+    test.world
+        .check_account(ESDT_SAFE_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    let refund_batch = test
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .get_first_batch_any_status_versioned()
+        .returns(ReturnsResult)
+        .run();
+    let (_, _, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    let refund_txs = refund_txs.to_vec();
+    assert_eq!(refund_txs.len(), 1);
+
+    // never credited to the BridgeProxy, even if rejected on the Ethereum side
+    let refund_tx = refund_txs.get(0);
+    assert!(refund_tx.is_refund_tx);
+    assert!(refund_tx.refund_reason == RefundReason::ProxyBackTransfersRefunded);
+    assert_eq!(
+        &refund_tx.from,
+        ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()).as_managed_buffer()
+    );
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .settle_transaction(1u32)
+        .returns(ExpectError(4, "Nothing to settle"))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_non_bridged_back_transfers_forwarded_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.config_bridge();
+    test.esdt_safe_deploy();
+
+    let mut calls = ManagedVec::new();
+    calls.push(CallStep {
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from(b"wrapTokens"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::none(),
+        },
+    });
+    let call_data: MultiCallData<StaticApi> = MultiCallData::new(calls);
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(500u64),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    // the wrapped token can not be bridged back, so it goes to the recipient
+    test.world
+        .check_account(BRIDGED_TOKENS_WRAPPER_ADDRESS)
+        .esdt_balance(WBRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    test.world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .settle_transaction(1u32)
+        .returns(ExpectError(4, "Nothing to settle"))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_call_allowlist_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+    test.deploy_crowdfunding();
+    test.config_bridge();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .add_allowed_target(
+            ManagedAddress::from(USER_ADDRESS.eval_to_array()),
+            MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new(),
+        )
+        .returns(ExpectError(4, "Target must be a smart contract"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_allowlist_enabled(true)
+        .run();
+
+    let mut endpoints = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+    endpoints.push(ManagedBuffer::from(b"fund"));
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .add_allowed_target(
+            ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+            endpoints,
+        )
+        .run();
+
+    let allowed_targets = test
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .allowed_targets()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(allowed_targets.to_vec().len(), 1);
+
+    let call_data: CallData<StaticApi> = CallData {
+        endpoint: ManagedBuffer::from(b"fund"),
+        gas_limit: GAS_LIMIT,
+        args: ManagedOption::none(),
+    };
+    let call_data = ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx = EthTransaction {
+        from: EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        to: ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()),
+        token_id: BRIDGE_TOKEN_ID.into(),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+
+    test.world
+        .tx()
+        .from(MULTI_TRANSFER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .deposit(&eth_tx, 1u64)
+        .single_esdt(
+            &TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            0u64,
+            &BigUint::from(500u64),
+        )
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .gas(200_000_000)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .execute(1u32)
+        .run();
+
+    test.world
+        .query()
+        .to(CROWDFUNDING_ADDRESS)
+        .typed(crowdfunding_esdt_proxy::CrowdfundingProxy)
+        .get_current_funds()
+        .returns(ExpectValue(500u64))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .remove_allowed_target(ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .remove_allowed_target(ManagedAddress::from(CROWDFUNDING_ADDRESS.eval_to_array()))
+        .returns(ExpectError(4, "Target is not allowed"))
+        .run();
+}
+
+#[test]
+fn bridge_proxy_sc_call_gas_limits_test() {
+    let mut test = BridgeProxyTestState::new();
+
+    test.bridge_proxy_deploy();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .min_gas_limit_for_sc_call()
+        .returns(ExpectValue(10_000_000u64))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_sc_call_gas_limits(GAS_LIMIT, GAS_LIMIT - 1)
+        .returns(ExpectError(4, "Invalid gas limits"))
+        .run();
+
+    test.world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_sc_call_gas_limits(1_000_000u64, GAS_LIMIT)
+        .run();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .min_gas_limit_for_sc_call()
+        .returns(ExpectValue(1_000_000u64))
+        .run();
+
+    test.world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .max_gas_limit_for_sc_call()
+        .returns(ExpectValue(GAS_LIMIT))
+        .run();
+}
@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           14
+// Endpoints:                           47
 // Async Callback (empty):               1
 // Promise callbacks:                    1
-// Total number of exported functions:  18
+// Total number of exported functions:  51
 
 #![no_std]
 
@@ -23,15 +23,48 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         deposit => deposit
         execute => execute
+        cancel => cancel
+        recoverStuckExecution => recover_stuck_execution
+        executeCalls => execute_calls
+        settleTransaction => settle_transaction
+        indexPendingTransactions => index_pending_transactions
         getPendingTransactionById => get_pending_transaction_by_id
         getPendingTransactions => get_pending_transactions
+        getPendingTransactionsCount => get_pending_transactions_count
+        getPendingTransactionsPage => get_pending_transactions_page
+        getPendingTransactionsByRecipient => get_pending_transactions_by_recipient
+        getPendingTransactionsByBatchId => get_pending_transactions_by_batch_id
+        getPendingTransactionsWithPayments => get_pending_transactions_with_payments
+        getTransactionStatus => get_transaction_status
         setMultiTransferAddress => set_multi_transfer_contract_address
         setBridgedTokensWrapperAddress => set_bridged_tokens_wrapper_contract_address
         setEsdtSafeAddress => set_esdt_safe_contract_address
+        setExecutorFeePercentage => set_executor_fee_percentage
+        setScCallGasLimits => set_sc_call_gas_limits
+        setExecutionGasFeeEnabled => set_execution_gas_fee_enabled
         getMultiTransferAddress => multi_transfer_address
         getBridgedTokensWrapperAddress => bridged_tokens_wrapper_address
         getEsdtSafeContractAddress => esdt_safe_contract_address
+        getUnsettledPayments => unsettled_payments
+        getExecutorFeePercentage => executor_fee_percentage
+        isExecutionGasFeeEnabled => execution_gas_fee_enabled
+        getMinGasLimitForScCall => min_gas_limit_for_sc_call
+        getMaxGasLimitForScCall => max_gas_limit_for_sc_call
         highestTxId => highest_tx_id
+        setAllowlistEnabled => set_allowlist_enabled
+        addAllowedTarget => add_allowed_target
+        removeAllowedTarget => remove_allowed_target
+        isAllowlistEnabled => allowlist_enabled
+        getAllowedTargets => allowed_targets
+        getAllowedEndpoints => allowed_endpoints
+        setFeeEstimatorContractAddress => set_fee_estimator_contract_address
+        setEthTxGasLimit => set_eth_tx_gas_limit
+        setDefaultPricePerGasUnit => set_default_price_per_gas_unit
+        setTokenTicker => set_token_ticker
+        calculateRequiredFee => calculate_required_fee
+        getFeeEstimatorContractAddress => fee_estimator_contract_address
+        getDefaultPricePerGasUnit => default_price_per_gas_unit
+        getEthTxGasLimit => eth_tx_gas_limit
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
@@ -6,7 +6,7 @@ mod events;
 use core::ops::Deref;
 
 pub use dfp_big_uint::DFPBigUint;
-use transaction::PaymentsVec;
+use transaction::{refund_reason::RefundReason, PaymentsVec};
 
 use eth_address::*;
 use multiversx_sc::imports::*;
@@ -15,7 +15,9 @@ impl<M: ManagedTypeApi> DFPBigUint<M> {}
 
 #[multiversx_sc::contract]
 pub trait BridgedTokensWrapper:
-    multiversx_sc_modules::pause::PauseModule + events::EventsModule
+    multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+    + token_pause_module::TokenPauseModule
 {
     #[init]
     fn init(&self) {
@@ -154,6 +156,8 @@ pub trait BridgedTokensWrapper:
         let mut new_payments = ManagedVec::new();
 
         for payment in &original_payments {
+            self.require_token_not_paused(&payment.token_identifier);
+
             let universal_token_id_mapper =
                 self.chain_specific_to_universal_mapping(&payment.token_identifier);
 
@@ -205,6 +209,8 @@ pub trait BridgedTokensWrapper:
 
     fn unwrap_token_common(&self, requested_token: &TokenIdentifier) -> BigUint {
         require!(self.not_paused(), "Contract is paused");
+        self.require_token_not_paused(requested_token);
+
         let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
         require!(payment_amount > 0u32, "Must pay more than 0 tokens!");
 
@@ -262,6 +268,7 @@ pub trait BridgedTokensWrapper:
                     initial_batch_id: 0,
                     initial_nonce: 0,
                 }),
+                OptionalValue::<RefundReason>::None,
             )
             .single_esdt(&requested_token, 0, &converted_amount)
             .sync_call();
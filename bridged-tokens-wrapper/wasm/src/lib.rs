@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           17
+// Endpoints:                           20
 // Async Callback (empty):               1
-// Total number of exported functions:  20
+// Total number of exported functions:  23
 
 #![no_std]
 
@@ -37,6 +37,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
+        pauseToken => pause_token
+        unpauseToken => unpause_token
+        isTokenPaused => token_paused
     )
 }
 
@@ -0,0 +1,141 @@
+#![no_std]
+
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+pub const CIRCUIT_BREAKER_TRIP_REASON_STORAGE_KEY: &[u8] = b"circuitBreakerTripReason";
+pub const DEFAULT_OUTFLOW_WINDOW_DURATION: u64 = 14_400; // ~1 day
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq)]
+pub enum CircuitBreakerReason {
+    None,
+    OutflowThresholdExceeded,
+    MintBurnInvariantViolated,
+}
+
+/// Automatically pauses the contract when the tokens leaving it look anomalous.
+///
+/// Outflows are tracked per token, in windows of `outflowWindowDuration` blocks.
+/// The transfer that pushes a window over its threshold is still completed,
+/// everything after it is blocked until the breaker is reset.
+///
+/// Unlike a regular pause, a tripped breaker can only be cleared through `resetCircuitBreaker`,
+/// which the Multisig only calls as a quorum action.
+#[multiversx_sc::module]
+pub trait CircuitBreakerModule: multiversx_sc_modules::pause::PauseModule {
+    /// Max amount of the given token that may leave the contract in a single window.
+    /// 0 disables outflow tracking for the token.
+    #[only_owner]
+    #[endpoint(setOutflowThreshold)]
+    fn set_outflow_threshold(&self, token_id: TokenIdentifier, threshold: BigUint) {
+        self.outflow_threshold(&token_id).set(&threshold);
+    }
+
+    #[only_owner]
+    #[endpoint(setOutflowWindowDuration)]
+    fn set_outflow_window_duration(&self, window_duration: u64) {
+        require!(
+            window_duration > 0,
+            "Outflow window duration must be more than 0"
+        );
+
+        self.outflow_window_duration().set(window_duration);
+    }
+
+    #[only_owner]
+    #[endpoint(resetCircuitBreaker)]
+    fn reset_circuit_breaker(&self) {
+        require!(
+            self.is_circuit_breaker_tripped(),
+            "Circuit breaker is not tripped"
+        );
+
+        self.circuit_breaker_trip_reason().clear();
+        self.set_paused(false);
+
+        self.circuit_breaker_reset_event();
+    }
+
+    // private
+
+    fn record_outflow(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        let threshold = self.outflow_threshold(token_id).get();
+        if threshold == 0 {
+            return;
+        }
+
+        let current_block_nonce = self.blockchain().get_block_nonce();
+        let window_duration = self.outflow_window_duration().get();
+        let window_start_mapper = self.outflow_window_start(token_id);
+        let outflow_mapper = self.outflow_in_window(token_id);
+
+        let window_end = window_start_mapper.get() + window_duration;
+        let total_outflow = if current_block_nonce >= window_end {
+            window_start_mapper.set(current_block_nonce);
+            outflow_mapper.set(amount);
+
+            amount.clone()
+        } else {
+            outflow_mapper.update(|outflow| {
+                *outflow += amount;
+                outflow.clone()
+            })
+        };
+
+        if total_outflow > threshold {
+            self.trip_circuit_breaker(token_id, CircuitBreakerReason::OutflowThresholdExceeded);
+        }
+    }
+
+    fn trip_circuit_breaker(&self, token_id: &TokenIdentifier, reason: CircuitBreakerReason) {
+        self.set_paused(true);
+        self.circuit_breaker_trip_reason().set(reason);
+
+        self.circuit_breaker_tripped_event(token_id, reason);
+    }
+
+    fn is_circuit_breaker_tripped(&self) -> bool {
+        self.circuit_breaker_trip_reason().get() != CircuitBreakerReason::None
+    }
+
+    fn require_circuit_breaker_not_tripped(&self) {
+        require!(
+            !self.is_circuit_breaker_tripped(),
+            "Circuit breaker tripped, waiting for reset"
+        );
+    }
+
+    // events
+
+    #[event("circuitBreakerTripped")]
+    fn circuit_breaker_tripped_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] reason: CircuitBreakerReason,
+    );
+
+    #[event("circuitBreakerReset")]
+    fn circuit_breaker_reset_event(&self);
+
+    // storage
+
+    #[view(getOutflowThreshold)]
+    #[storage_mapper("outflowThreshold")]
+    fn outflow_threshold(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getOutflowWindowDuration)]
+    #[storage_mapper("outflowWindowDuration")]
+    fn outflow_window_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("outflowWindowStart")]
+    fn outflow_window_start(&self, token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    #[view(getOutflowInWindow)]
+    #[storage_mapper("outflowInWindow")]
+    fn outflow_in_window(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getCircuitBreakerTripReason)]
+    #[storage_mapper("circuitBreakerTripReason")]
+    fn circuit_breaker_trip_reason(&self) -> SingleValueMapper<CircuitBreakerReason>;
+}
@@ -48,6 +48,19 @@ pub trait FeeEstimatorModule {
         price_per_gas_unit * gas_limit
     }
 
+    /// Same as `calculate_required_fee`, but also covers the gas of a call
+    /// executed on the Ethereum side after the transfer
+    fn calculate_required_fee_with_call_gas(
+        &self,
+        token_id: &TokenIdentifier,
+        call_gas_limit: u64,
+    ) -> BigUint {
+        let price_per_gas_unit = self.get_price_per_gas_unit(token_id);
+        let gas_limit = self.eth_tx_gas_limit().get() + BigUint::from(call_gas_limit);
+
+        price_per_gas_unit * gas_limit
+    }
+
     fn get_price_per_gas_unit(&self, token_id: &TokenIdentifier) -> BigUint {
         let opt_price = self.get_aggregator_mapping(&TokenIdentifier::from(GWEI_STRING), token_id);
 
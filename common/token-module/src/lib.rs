@@ -3,6 +3,8 @@
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
+use circuit_breaker_module::CircuitBreakerReason;
+
 pub const PERCENTAGE_TOTAL: u32 = 10_000; // precision of 2 decimals
 pub static INVALID_PERCENTAGE_SUM_OVER_ERR_MSG: &[u8] = b"Percentages do not add up to 100%";
 
@@ -14,7 +16,11 @@ pub struct AddressPercentagePair<M: ManagedTypeApi> {
 }
 
 #[multiversx_sc::module]
-pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
+pub trait TokenModule:
+    fee_estimator_module::FeeEstimatorModule
+    + circuit_breaker_module::CircuitBreakerModule
+    + token_pause_module::TokenPauseModule
+{
     // endpoints - owner-only
 
     /// Distributes the accumulated fees to the given addresses.
@@ -135,6 +141,16 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
             "Only MultiTransfer can get tokens"
         );
 
+        if self.is_token_paused(token_id) {
+            return false;
+        }
+
+        // nothing leaves the contract once the breaker tripped,
+        // the MultiTransfer halts the rest of the batch when it sees the trip
+        if self.is_circuit_breaker_tripped() {
+            return false;
+        }
+
         if !self.mint_burn_token(token_id).get() {
             let total_balances_mapper = self.total_balances(token_id);
             if &total_balances_mapper.get() >= amount {
@@ -145,6 +161,7 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
                     .to(ToCaller)
                     .single_esdt(token_id, 0, amount)
                     .transfer();
+                self.record_outflow(token_id, amount);
 
                 return true;
             } else {
@@ -154,11 +171,12 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
 
         let burn_balances_mapper = self.burn_balances(token_id);
         let mint_balances_mapper = self.mint_balances(token_id);
-        if self.native_token(token_id).get() {
-            require!(
-                burn_balances_mapper.get() >= &mint_balances_mapper.get() + amount,
-                "Not enough burned tokens!"
-            );
+        if self.native_token(token_id).get()
+            && burn_balances_mapper.get() < &mint_balances_mapper.get() + amount
+        {
+            // minting would put more tokens in circulation than were ever burned
+            self.trip_circuit_breaker(token_id, CircuitBreakerReason::MintBurnInvariantViolated);
+            return false;
         }
 
         let mint_executed = self.internal_mint(token_id, amount);
@@ -173,6 +191,7 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
         mint_balances_mapper.update(|minted| {
             *minted += amount;
         });
+        self.record_outflow(token_id, amount);
 
         true
     }
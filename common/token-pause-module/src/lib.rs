@@ -0,0 +1,40 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Pauses bridging for a single token, leaving all the other tokens untouched.
+/// Used alongside the contract-wide `PauseModule`, e.g. when only one ERC20 is compromised.
+#[multiversx_sc::module]
+pub trait TokenPauseModule {
+    #[only_owner]
+    #[endpoint(pauseToken)]
+    fn pause_token(&self, token_id: TokenIdentifier) {
+        self.token_paused(&token_id).set(true);
+        self.pause_token_event(&token_id);
+    }
+
+    #[only_owner]
+    #[endpoint(unpauseToken)]
+    fn unpause_token(&self, token_id: TokenIdentifier) {
+        self.token_paused(&token_id).clear();
+        self.unpause_token_event(&token_id);
+    }
+
+    fn is_token_paused(&self, token_id: &TokenIdentifier) -> bool {
+        self.token_paused(token_id).get()
+    }
+
+    fn require_token_not_paused(&self, token_id: &TokenIdentifier) {
+        require!(!self.is_token_paused(token_id), "Token is paused");
+    }
+
+    #[event("pauseToken")]
+    fn pause_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[event("unpauseToken")]
+    fn unpause_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[view(isTokenPaused)]
+    #[storage_mapper("tokenPaused")]
+    fn token_paused(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+}
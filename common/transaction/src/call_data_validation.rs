@@ -0,0 +1,91 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::{multi_call_data::MultiCallData, CallData};
+
+/// Why the call data of an Ethereum -> MultiversX smart contract call was rejected.
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, ManagedVecItem,
+)]
+pub enum CallDataError {
+    UndecodableCallData,
+    MissingEndpoint,
+    GasLimitTooLow,
+    GasLimitTooHigh,
+    CallNotAllowed,
+    InvalidTarget,
+}
+
+impl<M: ManagedTypeApi> CallData<M> {
+    pub fn validate(&self, min_gas_limit: u64, max_gas_limit: u64) -> Result<(), CallDataError> {
+        if self.endpoint.is_empty() {
+            return Err(CallDataError::MissingEndpoint);
+        }
+
+        validate_gas_limit(self.gas_limit, min_gas_limit, max_gas_limit)
+    }
+}
+
+impl<M: ManagedTypeApi> MultiCallData<M> {
+    /// The gas bounds apply to the whole sequence.
+    pub fn validate(&self, min_gas_limit: u64, max_gas_limit: u64) -> Result<(), CallDataError> {
+        if self.calls.is_empty() {
+            return Err(CallDataError::MissingEndpoint);
+        }
+
+        for step in self.calls.iter() {
+            if step.call_data.endpoint.is_empty() {
+                return Err(CallDataError::MissingEndpoint);
+            }
+            if step.call_data.gas_limit == 0 {
+                return Err(CallDataError::GasLimitTooLow);
+            }
+        }
+
+        validate_gas_limit(self.total_gas_limit(), min_gas_limit, max_gas_limit)
+    }
+}
+
+/// Validates call data in either the legacy or the multi-call format.
+/// No step of a multi-call may target the BridgeProxy itself.
+pub fn validate_raw_call_data<M: ManagedTypeApi>(
+    raw_call_data: &ManagedBuffer<M>,
+    bridge_proxy_address: &ManagedAddress<M>,
+    min_gas_limit: u64,
+    max_gas_limit: u64,
+) -> Result<(), CallDataError> {
+    if MultiCallData::is_multi_call(raw_call_data) {
+        let Ok(multi_call_data) = MultiCallData::<M>::top_decode(raw_call_data.clone()) else {
+            return Err(CallDataError::UndecodableCallData);
+        };
+
+        multi_call_data.validate(min_gas_limit, max_gas_limit)?;
+        if multi_call_data.has_step_to(bridge_proxy_address) {
+            return Err(CallDataError::InvalidTarget);
+        }
+
+        return Ok(());
+    }
+
+    let Ok(call_data) = CallData::<M>::top_decode(raw_call_data.clone()) else {
+        return Err(CallDataError::UndecodableCallData);
+    };
+
+    call_data.validate(min_gas_limit, max_gas_limit)
+}
+
+fn validate_gas_limit(
+    gas_limit: u64,
+    min_gas_limit: u64,
+    max_gas_limit: u64,
+) -> Result<(), CallDataError> {
+    if gas_limit < min_gas_limit {
+        return Err(CallDataError::GasLimitTooLow);
+    }
+    if gas_limit > max_gas_limit {
+        return Err(CallDataError::GasLimitTooHigh);
+    }
+
+    Ok(())
+}
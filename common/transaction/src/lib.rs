@@ -1,14 +1,23 @@
 #![no_std]
 
+use multiversx_sc::codec::{DecodeError, DecodeErrorHandler, NestedDecodeInput, TopDecodeInput};
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
 use eth_address::EthAddress;
+use refund_reason::RefundReason;
+pub mod call_data_validation;
+pub mod multi_call_data;
+pub mod refund_reason;
 pub mod transaction_status;
 
 // revert protection
 pub const MIN_BLOCKS_FOR_FINALITY: u64 = 10;
+// number of results per transaction in the legacy, field-split batch views
 pub const TX_MULTIRESULT_NR_FIELDS: usize = 6;
+// layout version of the `Transaction` structs returned by the versioned batch views,
+// to be bumped whenever `Transaction` changes
+pub const TX_BATCH_VERSION: u8 = 1;
 
 pub type TxNonce = u64;
 pub type BlockNonce = u64;
@@ -24,6 +33,7 @@ pub type TxAsMultiValue<M> = MultiValue6<
 >;
 pub type PaymentsVec<M> = ManagedVec<M, EsdtTokenPayment<M>>;
 pub type TxBatchSplitInFields<M> = MultiValue2<u64, MultiValueEncoded<M, TxAsMultiValue<M>>>;
+pub type VersionedTxBatch<M> = MultiValue3<u8, u64, MultiValueEncoded<M, Transaction<M>>>;
 
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopDecode, TopEncode, Clone, ManagedVecItem)]
@@ -43,6 +53,16 @@ impl<M: ManagedTypeApi> Default for CallData<M> {
     }
 }
 
+/// Call to be executed on the Ethereum side, after a MultiversX -> Ethereum transfer.
+/// Stored top-encoded in `Transaction::call_data`.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, ManagedVecItem)]
+pub struct OutboundCallData<M: ManagedTypeApi> {
+    pub target: EthAddress<M>,
+    pub data: ManagedBuffer<M>,
+    pub gas_limit: u64,
+}
+
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, ManagedVecItem)]
 pub struct EthTransaction<M: ManagedTypeApi> {
@@ -64,7 +84,7 @@ pub type EthTxAsMultiValue<M> = MultiValue6<
 >;
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+#[derive(TopEncode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct Transaction<M: ManagedTypeApi> {
     pub block_nonce: BlockNonce,
     pub nonce: TxNonce,
@@ -73,6 +93,53 @@ pub struct Transaction<M: ManagedTypeApi> {
     pub token_identifier: TokenIdentifier<M>,
     pub amount: BigUint<M>,
     pub is_refund_tx: bool,
+    pub refund_reason: RefundReason,
+    pub original_batch_id: u64,
+    pub call_data: ManagedOption<M, ManagedBuffer<M>>,
+}
+
+/// Transactions stored before `refund_reason`, `original_batch_id` and `call_data` were added
+/// end right after `is_refund_tx`, so the missing fields are decoded as their defaults.
+impl<M: ManagedTypeApi> TopDecode for Transaction<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut nested_buffer = input.into_nested_buffer();
+        let block_nonce = BlockNonce::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        let nonce = TxNonce::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        let from = ManagedBuffer::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        let to = ManagedBuffer::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        let token_identifier = TokenIdentifier::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        let amount = BigUint::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        let is_refund_tx = bool::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+
+        let mut tx = Transaction {
+            block_nonce,
+            nonce,
+            from,
+            to,
+            token_identifier,
+            amount,
+            is_refund_tx,
+            refund_reason: RefundReason::None,
+            original_batch_id: 0,
+            call_data: ManagedOption::none(),
+        };
+        if nested_buffer.is_depleted() {
+            return Ok(tx);
+        }
+
+        tx.refund_reason = RefundReason::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        tx.original_batch_id = u64::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        tx.call_data = ManagedOption::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        if !nested_buffer.is_depleted() {
+            return Err(h.handle_error(DecodeError::INPUT_TOO_LONG));
+        }
+
+        Ok(tx)
+    }
 }
 
 impl<M: ManagedTypeApi> From<TxAsMultiValue<M>> for Transaction<M> {
@@ -88,6 +155,9 @@ impl<M: ManagedTypeApi> From<TxAsMultiValue<M>> for Transaction<M> {
             token_identifier,
             amount,
             is_refund_tx: false,
+            refund_reason: RefundReason::None,
+            original_batch_id: 0,
+            call_data: ManagedOption::none(),
         }
     }
 }
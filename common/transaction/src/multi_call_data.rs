@@ -0,0 +1,58 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::CallData;
+
+/// Version tag of the multi-call format.
+/// Legacy `CallData` always starts with a zero byte (the high byte of the endpoint length),
+/// so the leading byte is enough to tell the two formats apart.
+pub const MULTI_CALL_DATA_VERSION: u8 = 1;
+
+/// A single step of a multi-call.
+/// Each step receives the tokens sent back by the previous one.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, ManagedVecItem)]
+pub struct CallStep<M: ManagedTypeApi> {
+    pub to: ManagedAddress<M>,
+    pub call_data: CallData<M>,
+}
+
+/// Tokens left over after the last step are sent to `fallback_address`, if provided.
+/// Otherwise, they are bridged back to the Ethereum sender.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub struct MultiCallData<M: ManagedTypeApi> {
+    pub version: u8,
+    pub fallback_address: ManagedOption<M, ManagedAddress<M>>,
+    pub calls: ManagedVec<M, CallStep<M>>,
+}
+
+impl<M: ManagedTypeApi> MultiCallData<M> {
+    pub fn new(calls: ManagedVec<M, CallStep<M>>) -> Self {
+        MultiCallData {
+            version: MULTI_CALL_DATA_VERSION,
+            fallback_address: ManagedOption::none(),
+            calls,
+        }
+    }
+
+    pub fn with_fallback_address(mut self, fallback_address: ManagedAddress<M>) -> Self {
+        self.fallback_address = ManagedOption::some(fallback_address);
+        self
+    }
+
+    pub fn is_multi_call(raw_call_data: &ManagedBuffer<M>) -> bool {
+        let mut version = [0u8; 1];
+        raw_call_data.load_slice(0, &mut version).is_ok() && version[0] == MULTI_CALL_DATA_VERSION
+    }
+
+    pub fn has_step_to(&self, address: &ManagedAddress<M>) -> bool {
+        self.calls.iter().any(|step| &step.to == address)
+    }
+
+    pub fn total_gas_limit(&self) -> u64 {
+        self.calls.iter().fold(0u64, |total, step| {
+            total.saturating_add(step.call_data.gas_limit)
+        })
+    }
+}
@@ -0,0 +1,23 @@
+use multiversx_sc::derive_imports::*;
+
+/// Why an Ethereum -> MultiversX transfer was refunded.
+/// `None` is used for regular transactions.
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, ManagedVecItem,
+)]
+pub enum RefundReason {
+    None,
+    InvalidDestination,
+    OverMaxAmount,
+    FrozenDestinationAccount,
+    InvalidToken,
+    TokenPaused,
+    ProxyExecutionFailed,
+    QuarantineRefunded,
+    ClaimExpired,
+    InvalidCallData,
+    ProxyBackTransfersRefunded,
+    ProxyTransactionCancelled,
+    ExecutionGasFeeAboveAmount,
+}
@@ -3,7 +3,9 @@
 use multiversx_sc::imports::*;
 
 pub use batch_status::BatchStatus;
-use transaction::{Transaction, TxBatchSplitInFields, MIN_BLOCKS_FOR_FINALITY};
+use transaction::{
+    Transaction, TxBatchSplitInFields, VersionedTxBatch, MIN_BLOCKS_FOR_FINALITY, TX_BATCH_VERSION,
+};
 use tx_batch_mapper::TxBatchMapper;
 
 pub mod batch_status;
@@ -38,6 +40,8 @@ pub trait TxBatchModule {
 
     // views
 
+    /// Legacy layout, without the refund info and the call data of the transactions,
+    /// see `getCurrentTxBatchVersioned`.
     #[view(getCurrentTxBatch)]
     fn get_current_tx_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
         let first_batch_id = self.first_batch_id().get();
@@ -78,6 +82,38 @@ pub trait TxBatchModule {
         OptionalValue::Some((batch_id, result_vec).into())
     }
 
+    /// Same as `getCurrentTxBatch`, but returns a layout version tag, the batch ID,
+    /// then full `Transaction` structs, including the refund info and the call data.
+    #[view(getCurrentTxBatchVersioned)]
+    fn get_current_tx_batch_versioned(&self) -> OptionalValue<VersionedTxBatch<Self::Api>> {
+        let first_batch_id = self.first_batch_id().get();
+        let first_batch = self.pending_batches(first_batch_id);
+
+        if self.is_batch_full(&first_batch, first_batch_id, first_batch_id)
+            && self.is_batch_final(&first_batch)
+        {
+            return OptionalValue::Some(self.to_versioned_batch(first_batch_id, &first_batch));
+        }
+
+        OptionalValue::None
+    }
+
+    #[view(getFirstBatchAnyStatusVersioned)]
+    fn get_first_batch_any_status_versioned(&self) -> OptionalValue<VersionedTxBatch<Self::Api>> {
+        let first_batch_id = self.first_batch_id().get();
+        self.get_batch_versioned(first_batch_id)
+    }
+
+    #[view(getBatchVersioned)]
+    fn get_batch_versioned(&self, batch_id: u64) -> OptionalValue<VersionedTxBatch<Self::Api>> {
+        let tx_batch = self.pending_batches(batch_id);
+        if tx_batch.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.to_versioned_batch(batch_id, &tx_batch))
+    }
+
     #[view(getBatchStatus)]
     fn get_batch_status(&self, batch_id: u64) -> BatchStatus<Self::Api> {
         let first_batch_id = self.first_batch_id().get();
@@ -114,6 +150,19 @@ pub trait TxBatchModule {
 
     // private
 
+    fn to_versioned_batch(
+        &self,
+        batch_id: u64,
+        tx_batch: &TxBatchMapper<Self::Api>,
+    ) -> VersionedTxBatch<Self::Api> {
+        let mut result_vec = MultiValueEncoded::new();
+        for tx in tx_batch.iter() {
+            result_vec.push(tx);
+        }
+
+        (TX_BATCH_VERSION, batch_id, result_vec).into()
+    }
+
     fn add_to_batch(&self, transaction: Transaction<Self::Api>) -> u64 {
         let first_batch_id = self.first_batch_id().get();
         let last_batch_id = self.last_batch_id().get();
@@ -6,10 +6,16 @@ multiversx_sc::derive_imports!();
 
 use core::convert::TryFrom;
 
+use circuit_breaker_module::DEFAULT_OUTFLOW_WINDOW_DURATION;
 use core::ops::Deref;
 use eth_address::*;
 use fee_estimator_module::GWEI_STRING;
-use transaction::{transaction_status::TransactionStatus, Transaction};
+use transaction::{
+    refund_reason::RefundReason, transaction_status::TransactionStatus, OutboundCallData,
+    Transaction,
+};
+
+pub mod egld_wrapper_proxy;
 
 const DEFAULT_MAX_TX_BATCH_SIZE: usize = 10;
 const DEFAULT_MAX_TX_BATCH_BLOCK_DURATION: u64 = 100; // ~10 minutes
@@ -31,6 +37,8 @@ pub trait EsdtSafe:
     + tx_batch_module::TxBatchModule
     + max_bridged_amount_module::MaxBridgedAmountModule
     + multiversx_sc_modules::pause::PauseModule
+    + circuit_breaker_module::CircuitBreakerModule
+    + token_pause_module::TokenPauseModule
 {
     /// fee_estimator_contract_address - The address of a Price Aggregator contract,
     /// which will get the price of token A in token B
@@ -55,6 +63,8 @@ pub trait EsdtSafe:
             .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
         self.max_tx_batch_block_duration()
             .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+        self.outflow_window_duration()
+            .set_if_empty(DEFAULT_OUTFLOW_WINDOW_DURATION);
 
         // batch ID 0 is considered invalid
         self.first_batch_id().set_if_empty(1);
@@ -89,6 +99,8 @@ pub trait EsdtSafe:
             .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
         self.max_tx_batch_block_duration()
             .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+        self.outflow_window_duration()
+            .set_if_empty(DEFAULT_OUTFLOW_WINDOW_DURATION);
 
         // batch ID 0 is considered invalid
         self.first_batch_id().set_if_empty(1);
@@ -180,6 +192,7 @@ pub trait EsdtSafe:
         let mut cached_prices = ManagedVec::<Self::Api, BigUint>::new();
         let mut new_transactions = ManagedVec::new();
         let mut original_tx_nonces = ManagedVec::<Self::Api, u64>::new();
+        let mut refund_fees = ManagedVec::<Self::Api, BigUint>::new();
 
         for (refund_tx, refund_payment) in refund_transactions.iter().zip(refund_payments.iter()) {
             require!(
@@ -191,27 +204,28 @@ pub trait EsdtSafe:
                 "Amounts do not match"
             );
 
-            let required_fee = match cached_token_ids
-                .iter()
-                .position(|id| *id == refund_tx.token_identifier)
-            {
-                Some(index) => (*cached_prices.get(index)).clone(),
-                None => {
-                    let queried_fee = self.calculate_required_fee(&refund_tx.token_identifier);
-                    cached_token_ids.push(refund_tx.token_identifier.clone());
-                    cached_prices.push(queried_fee.clone());
-
-                    queried_fee
-                }
-            };
+            let required_fee = self.get_cached_required_fee(
+                &refund_tx.token_identifier,
+                &mut cached_token_ids,
+                &mut cached_prices,
+            );
 
+            // the tokens are kept in the contract, until the owner decides what to do with them
             if refund_tx.amount <= required_fee {
+                self.unrefundable_dust(&refund_tx.token_identifier)
+                    .update(|dust| *dust += &refund_tx.amount);
+                self.unrefundable_dust_event(
+                    &refund_tx.token_identifier,
+                    &refund_tx.amount,
+                    refund_tx.nonce,
+                );
+
                 continue;
             }
 
             let actual_bridged_amount = refund_tx.amount - &required_fee;
             self.refund_fees_for_ethereum(&refund_tx.token_identifier)
-                .update(|fees| *fees += required_fee);
+                .update(|fees| *fees += &required_fee);
             let tx_nonce = self.get_and_save_next_tx_id();
 
             // "from" and "to" are inverted, since this was initially an Ethereum -> MultiversX tx
@@ -223,150 +237,191 @@ pub trait EsdtSafe:
                 token_identifier: refund_tx.token_identifier.clone(),
                 amount: actual_bridged_amount.clone(),
                 is_refund_tx: true,
+                refund_reason: refund_tx.refund_reason,
+                original_batch_id: refund_tx.original_batch_id,
+                call_data: ManagedOption::none(),
             };
             new_transactions.push(new_tx);
             original_tx_nonces.push(refund_tx.nonce);
+            refund_fees.push(required_fee);
 
-            let refund_token_id = refund_tx.token_identifier;
-
-            if self.mint_burn_token(&refund_token_id).get() {
-                let burn_balances_mapper = self.burn_balances(&refund_token_id);
-                let mint_balances_mapper = self.mint_balances(&refund_token_id);
-                if !self.native_token(&refund_token_id).get() {
-                    require!(
-                        mint_balances_mapper.get()
-                            >= &burn_balances_mapper.get() + &actual_bridged_amount,
-                        "Not enough minted tokens!"
-                    );
-                }
-                let burn_executed = self.internal_burn(&refund_token_id, &actual_bridged_amount);
-                require!(burn_executed, "Cannot do the burn action!");
-                burn_balances_mapper.update(|burned| {
-                    *burned += &actual_bridged_amount;
-                });
-            } else {
-                self.total_balances(&refund_token_id).update(|total| {
-                    *total += &actual_bridged_amount;
-                });
-            }
+            self.lock_bridged_tokens(&refund_tx.token_identifier, &actual_bridged_amount);
         }
 
-        let batch_ids = self.add_multiple_tx_to_batch(&new_transactions);
-        for (i, tx) in new_transactions.iter().enumerate() {
-            let batch_id = batch_ids.get(i);
-            let original_tx_nonce = original_tx_nonces.get(i);
+        self.add_refund_transactions_to_batch(&new_transactions, &original_tx_nonces, &refund_fees);
+    }
 
-            self.add_refund_transaction_event(batch_id, tx.nonce, original_tx_nonce);
+    /// Same as `addRefundBatch`, but for failed Ethereum -> MultiversX transactions
+    /// whose tokens never left the EsdtSafe, e.g. because the token was paused.
+    ///
+    /// Since no tokens are moved, no fees are subtracted and the balances are left untouched.
+    #[endpoint(addUnfundedRefundBatch)]
+    fn add_unfunded_refund_batch(&self, refund_transactions: ManagedVec<Transaction<Self::Api>>) {
+        let caller = self.blockchain().get_caller();
+        let multi_transfer_address = self.multi_transfer_contract_address().get();
+        require!(caller == multi_transfer_address, "Invalid caller");
+
+        let block_nonce = self.blockchain().get_block_nonce();
+        let mut new_transactions = ManagedVec::new();
+        let mut original_tx_nonces = ManagedVec::<Self::Api, u64>::new();
+        let mut refund_fees = ManagedVec::<Self::Api, BigUint>::new();
+
+        for refund_tx in refund_transactions.iter() {
+            let tx_nonce = self.get_and_save_next_tx_id();
+
+            // "from" and "to" are inverted, since this was initially an Ethereum -> MultiversX tx
+            let new_tx = Transaction {
+                block_nonce,
+                nonce: tx_nonce,
+                from: refund_tx.to,
+                to: refund_tx.from,
+                token_identifier: refund_tx.token_identifier,
+                amount: refund_tx.amount,
+                is_refund_tx: true,
+                refund_reason: refund_tx.refund_reason,
+                original_batch_id: refund_tx.original_batch_id,
+                call_data: ManagedOption::none(),
+            };
+            new_transactions.push(new_tx);
+            original_tx_nonces.push(refund_tx.nonce);
+            refund_fees.push(BigUint::zero());
         }
+
+        self.add_refund_transactions_to_batch(&new_transactions, &original_tx_nonces, &refund_fees);
     }
 
     // endpoints
 
     /// Create an MultiversX -> Ethereum transaction. Only fungible tokens are accepted.
+    /// Native EGLD is also accepted if an EGLD wrapper contract is set, and bridged as wrapped EGLD.
     ///
     /// Every transfer will have a part of the tokens subtracted as fees.
     /// The fee amount depends on the global eth_tx_gas_limit
     /// and the current GWEI price, respective to the bridged token
     ///
     /// fee_amount = price_per_gas_unit * eth_tx_gas_limit
+    ///
+    /// The BridgeProxy passes the reason of its refunds, `ProxyExecutionFailed` is assumed otherwise.
     #[payable("*")]
     #[endpoint(createTransaction)]
     fn create_transaction(
         &self,
         to: EthAddress<Self::Api>,
         opt_refund_info: OptionalValue<RefundInfo<Self::Api>>,
+        opt_refund_reason: OptionalValue<RefundReason>,
     ) {
-        require!(self.not_paused(), "Cannot create transaction while paused");
-
-        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
-        self.require_token_in_whitelist(&payment_token);
+        self.create_transaction_common(to, opt_refund_info, opt_refund_reason, None);
+    }
 
-        let required_fee = self.calculate_required_fee(&payment_token);
+    /// Same as `createTransaction`, but the Ethereum side also calls `target` with `data`
+    /// after the transfer, using at most `gas_limit` gas.
+    ///
+    /// The gas of the call is paid for as well:
+    /// fee_amount = price_per_gas_unit * (eth_tx_gas_limit + gas_limit)
+    #[payable("*")]
+    #[endpoint(createTransactionWithCallData)]
+    fn create_transaction_with_call_data(
+        &self,
+        to: EthAddress<Self::Api>,
+        target: EthAddress<Self::Api>,
+        data: ManagedBuffer,
+        gas_limit: u64,
+    ) {
+        let max_gas_limit = self.max_outbound_call_gas_limit().get();
+        require!(max_gas_limit > 0, "Outbound call data is not enabled");
+        require!(target != EthAddress::zero(), "Invalid call target");
+        require!(!data.is_empty(), "Empty call data");
         require!(
-            required_fee < payment_amount,
-            "Transaction fees cost more than the entire bridged amount"
+            gas_limit > 0 && gas_limit <= max_gas_limit,
+            "Invalid call gas limit"
         );
 
-        self.require_below_max_amount(&payment_token, &payment_amount);
-
-        // This addr is used for the refund, if the transaction fails
-        // This is passed by the BridgeTokenWrapper contract
-        let mut is_refund_tx = false;
-        let caller = self.blockchain().get_caller();
-        let refund_info = match opt_refund_info {
-            OptionalValue::Some(refund_info) => {
-                if caller == self.bridge_proxy_contract_address().get() {
-                    is_refund_tx = true;
-                    refund_info
-                } else if caller == self.bridged_tokens_wrapper_address().get() {
-                    refund_info
-                } else {
-                    sc_panic!("Cannot specify a refund address from this caller");
-                }
-            }
-            OptionalValue::None => RefundInfo {
-                address: caller,
-                initial_batch_id: 0,
-                initial_nonce: 0,
-            },
+        let call_data = OutboundCallData {
+            target,
+            data,
+            gas_limit,
         };
+        self.create_transaction_common(
+            to,
+            OptionalValue::None,
+            OptionalValue::None,
+            Some(call_data),
+        );
+    }
 
-        self.accumulated_transaction_fees(&payment_token)
-            .update(|fees| *fees += &required_fee);
+    /// Same as `createTransaction`, but for multiple payments at once.
+    /// `destinations` holds either a single address, used for all the payments,
+    /// or one address for each payment, in the same order.
+    ///
+    /// The fee is queried once per token.
+    #[payable("*")]
+    #[endpoint(createTransactions)]
+    fn create_transactions(&self, destinations: MultiValueEncoded<EthAddress<Self::Api>>) {
+        require!(self.not_paused(), "Cannot create transaction while paused");
+        self.require_circuit_breaker_not_tripped();
 
-        let actual_bridged_amount = payment_amount - required_fee.clone();
-        let tx_nonce = self.get_and_save_next_tx_id();
-        let tx = Transaction {
-            block_nonce: self.blockchain().get_block_nonce(),
-            nonce: tx_nonce,
-            from: refund_info.address.as_managed_buffer().clone(),
-            to: to.as_managed_buffer().clone(),
-            token_identifier: payment_token.clone(),
-            amount: actual_bridged_amount.clone(),
-            is_refund_tx,
-        };
+        let payments = self.call_value().all_esdt_transfers().deref().clone();
+        require!(!payments.is_empty(), "No payments");
 
-        let batch_id = self.add_to_batch(tx.clone());
-        if self.mint_burn_token(&payment_token).get() {
-            let burn_balances_mapper = self.burn_balances(&payment_token);
-            let mint_balances_mapper = self.mint_balances(&payment_token);
-            if !self.native_token(&payment_token).get() {
-                require!(
-                    mint_balances_mapper.get()
-                        >= &burn_balances_mapper.get() + &actual_bridged_amount,
-                    "Not enough minted tokens!"
-                );
-            }
-            let burn_executed = self.internal_burn(&payment_token, &actual_bridged_amount);
-            require!(burn_executed, "Cannot do the burn action!");
-            burn_balances_mapper.update(|burned| {
-                *burned += &actual_bridged_amount;
-            });
-        } else {
-            self.total_balances(&payment_token).update(|total| {
-                *total += &actual_bridged_amount;
-            });
+        let destinations = destinations.to_vec();
+        require!(
+            destinations.len() == 1 || destinations.len() == payments.len(),
+            "Invalid number of destinations"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let block_nonce = self.blockchain().get_block_nonce();
+        let mut cached_token_ids = ManagedVec::<Self::Api, TokenIdentifier>::new();
+        let mut cached_prices = ManagedVec::<Self::Api, BigUint>::new();
+        let mut new_transactions = ManagedVec::new();
+        let mut fees = ManagedVec::<Self::Api, BigUint>::new();
+
+        for (index, payment) in payments.iter().enumerate() {
+            require!(
+                payment.token_nonce == 0,
+                "Only fungible tokens are accepted"
+            );
+            let payment_token = payment.token_identifier;
+            let (actual_bridged_amount, required_fee) =
+                self.lock_bridged_payment(&payment_token, payment.amount, |token_id| {
+                    self.get_cached_required_fee(
+                        token_id,
+                        &mut cached_token_ids,
+                        &mut cached_prices,
+                    )
+                });
+            let to = if destinations.len() == 1 {
+                destinations.get(0)
+            } else {
+                destinations.get(index)
+            };
+            let tx = Transaction {
+                block_nonce,
+                nonce: self.get_and_save_next_tx_id(),
+                from: caller.as_managed_buffer().clone(),
+                to: to.as_managed_buffer().clone(),
+                token_identifier: payment_token,
+                amount: actual_bridged_amount,
+                is_refund_tx: false,
+                refund_reason: RefundReason::None,
+                original_batch_id: 0,
+                call_data: ManagedOption::none(),
+            };
+            new_transactions.push(tx);
+            fees.push(required_fee);
         }
-        if !is_refund_tx {
+
+        let batch_ids = self.add_multiple_tx_to_batch(&new_transactions);
+        for (index, tx) in new_transactions.iter().enumerate() {
             self.create_transaction_event(
-                batch_id,
-                tx_nonce,
-                payment_token,
-                actual_bridged_amount,
-                required_fee,
-                refund_info.address.as_managed_buffer().clone(),
+                batch_ids.get(index),
+                tx.nonce,
+                tx.token_identifier,
+                tx.amount,
+                (*fees.get(index)).clone(),
+                tx.from,
                 tx.to,
             );
-        } else {
-            self.create_refund_transaction_event(
-                batch_id,
-                tx_nonce,
-                payment_token,
-                actual_bridged_amount,
-                required_fee,
-                refund_info.initial_batch_id,
-                refund_info.initial_nonce,
-            );
         }
     }
 
@@ -375,6 +430,8 @@ pub trait EsdtSafe:
     /// For example, if the receiver is a SC, a frozen account, etc.
     #[endpoint(claimRefund)]
     fn claim_refund(&self, token_id: TokenIdentifier) -> EsdtTokenPayment<Self::Api> {
+        self.require_circuit_breaker_not_tripped();
+
         let caller = self.blockchain().get_caller();
         let refund_amount = self.refund_amount(&caller, &token_id).get();
         require!(refund_amount > 0, "Nothing to refund");
@@ -388,6 +445,7 @@ pub trait EsdtSafe:
             .to(ToCaller)
             .single_esdt(&token_id, 0, &refund_amount)
             .transfer();
+        self.record_outflow(&token_id, &refund_amount);
 
         self.claim_refund_transaction_event(&token_id, caller);
         EsdtTokenPayment::new(token_id, 0, refund_amount)
@@ -411,6 +469,33 @@ pub trait EsdtSafe:
         }
     }
 
+    #[only_owner]
+    #[endpoint(setEgldWrapperContractAddress)]
+    fn set_egld_wrapper_contract_address(&self, opt_new_address: OptionalValue<ManagedAddress>) {
+        match opt_new_address {
+            OptionalValue::Some(sc_addr) => {
+                require!(
+                    self.blockchain().is_smart_contract(&sc_addr),
+                    "Invalid EGLD wrapper contract address"
+                );
+
+                self.egld_wrapper_contract_address().set(&sc_addr);
+            }
+            OptionalValue::None => self.egld_wrapper_contract_address().clear(),
+        }
+    }
+
+    /// Max gas a call executed on the Ethereum side may request.
+    /// 0 disables `createTransactionWithCallData`.
+    ///
+    /// `getCurrentTxBatch` does not include the call data, so it must only be set above 0
+    /// once the relayers read the batches through `getCurrentTxBatchVersioned`.
+    #[only_owner]
+    #[endpoint(setMaxOutboundCallGasLimit)]
+    fn set_max_outbound_call_gas_limit(&self, max_gas_limit: u64) {
+        self.max_outbound_call_gas_limit().set(max_gas_limit);
+    }
+
     #[only_owner]
     #[endpoint(setBridgeProxyContractAddress)]
     fn set_bridge_proxy_contract_address(&self, opt_new_address: OptionalValue<ManagedAddress>) {
@@ -463,6 +548,56 @@ pub trait EsdtSafe:
         accumulated_transaction_fees_mapper.set(BigUint::zero());
     }
 
+    /// Sends the refunds that were too small to cover the fees to the given address.
+    #[only_owner]
+    #[endpoint(withdrawUnrefundableDust)]
+    fn withdraw_unrefundable_dust(
+        &self,
+        token_id: TokenIdentifier,
+        multisig_owner: ManagedAddress,
+    ) {
+        let unrefundable_dust_mapper = self.unrefundable_dust(&token_id);
+        let amount_out = unrefundable_dust_mapper.get();
+        require!(amount_out > 0, "There is no dust to withdraw");
+
+        unrefundable_dust_mapper.clear();
+        self.tx()
+            .to(multisig_owner)
+            .single_esdt(&token_id, 0, &amount_out)
+            .transfer();
+    }
+
+    /// Makes part of the unrefundable dust claimable by the given address, through `claimRefund`.
+    /// Usually the original sender, which can be found in the `unrefundableDust` events.
+    #[only_owner]
+    #[endpoint(recreditUnrefundableDust)]
+    fn recredit_unrefundable_dust(
+        &self,
+        token_id: TokenIdentifier,
+        receiver: ManagedAddress,
+        amount: BigUint,
+    ) {
+        require!(amount > 0, "Amount must be greater than 0");
+
+        let unrefundable_dust_mapper = self.unrefundable_dust(&token_id);
+        let dust = unrefundable_dust_mapper.get();
+        require!(amount <= dust, "Not enough dust");
+        unrefundable_dust_mapper.set(&(dust - &amount));
+
+        // the tokens are accounted as if bridged, since claiming rebalances them back
+        if self.mint_burn_token(&token_id).get() {
+            let burn_executed = self.internal_burn(&token_id, &amount);
+            require!(burn_executed, "Cannot do the burn action!");
+            self.burn_balances(&token_id)
+                .update(|burned| *burned += &amount);
+        } else {
+            self.total_balances(&token_id)
+                .update(|total| *total += &amount);
+        }
+
+        self.mark_refund(&receiver, &token_id, &amount);
+    }
+
     #[view(computeTotalAmmountsFromIndex)]
     fn compute_total_amounts_from_index(
         &self,
@@ -539,6 +674,31 @@ pub trait EsdtSafe:
         }
     }
 
+    #[view(getTotalUnrefundableDust)]
+    fn get_total_unrefundable_dust(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>> {
+        let mut dust_amounts = MultiValueEncoded::new();
+        for token_id in self.token_whitelist().iter() {
+            let amount = self.unrefundable_dust(&token_id).get();
+            if amount > 0u32 {
+                dust_amounts.push((token_id, amount).into());
+            }
+        }
+
+        dust_amounts
+    }
+
+    /// Returns the fee for a transfer followed by a call using `call_gas_limit` gas
+    #[view(calculateRequiredFeeWithCallData)]
+    fn calculate_required_fee_with_call_data(
+        &self,
+        token_id: TokenIdentifier,
+        call_gas_limit: u64,
+    ) -> BigUint {
+        self.calculate_required_fee_with_call_gas(&token_id, call_gas_limit)
+    }
+
     #[view(getTransactionFees)]
     fn get_transaction_fees(&self, token_id: TokenIdentifier) -> BigUint {
         let accumulated_transaction_fees_mapper = self.accumulated_transaction_fees(&token_id);
@@ -550,6 +710,221 @@ pub trait EsdtSafe:
     }
     // private
 
+    fn create_transaction_common(
+        &self,
+        to: EthAddress<Self::Api>,
+        opt_refund_info: OptionalValue<RefundInfo<Self::Api>>,
+        opt_refund_reason: OptionalValue<RefundReason>,
+        opt_call_data: Option<OutboundCallData<Self::Api>>,
+    ) {
+        require!(self.not_paused(), "Cannot create transaction while paused");
+        self.require_circuit_breaker_not_tripped();
+
+        let (payment_token, payment_amount) = self.get_bridged_payment();
+        let (actual_bridged_amount, required_fee) =
+            self.lock_bridged_payment(&payment_token, payment_amount, |token_id| {
+                match &opt_call_data {
+                    Some(call_data) => {
+                        self.calculate_required_fee_with_call_gas(token_id, call_data.gas_limit)
+                    }
+                    None => self.calculate_required_fee(token_id),
+                }
+            });
+
+        // This addr is used for the refund, if the transaction fails
+        // This is passed by the BridgeTokenWrapper contract
+        let mut is_refund_tx = false;
+        let caller = self.blockchain().get_caller();
+        let refund_info = match opt_refund_info {
+            OptionalValue::Some(refund_info) => {
+                if caller == self.bridge_proxy_contract_address().get() {
+                    is_refund_tx = true;
+                    refund_info
+                } else if caller == self.bridged_tokens_wrapper_address().get() {
+                    refund_info
+                } else {
+                    sc_panic!("Cannot specify a refund address from this caller");
+                }
+            }
+            OptionalValue::None => RefundInfo {
+                address: caller,
+                initial_batch_id: 0,
+                initial_nonce: 0,
+            },
+        };
+
+        let tx_nonce = self.get_and_save_next_tx_id();
+        let (refund_reason, original_batch_id) = match opt_refund_reason {
+            OptionalValue::Some(refund_reason) => {
+                require!(
+                    is_refund_tx,
+                    "Cannot specify a refund reason from this caller"
+                );
+                (refund_reason, refund_info.initial_batch_id)
+            }
+            OptionalValue::None if is_refund_tx => (
+                RefundReason::ProxyExecutionFailed,
+                refund_info.initial_batch_id,
+            ),
+            OptionalValue::None => (RefundReason::None, 0),
+        };
+        let call_data = match &opt_call_data {
+            Some(call_data) => {
+                let mut encoded_call_data = ManagedBuffer::new();
+                if call_data.top_encode(&mut encoded_call_data).is_err() {
+                    sc_panic!("Failed to serialize call data");
+                }
+
+                ManagedOption::some(encoded_call_data)
+            }
+            None => ManagedOption::none(),
+        };
+        let tx = Transaction {
+            block_nonce: self.blockchain().get_block_nonce(),
+            nonce: tx_nonce,
+            from: refund_info.address.as_managed_buffer().clone(),
+            to: to.as_managed_buffer().clone(),
+            token_identifier: payment_token.clone(),
+            amount: actual_bridged_amount.clone(),
+            is_refund_tx,
+            refund_reason,
+            original_batch_id,
+            call_data,
+        };
+
+        let batch_id = self.add_to_batch(tx.clone());
+        if !is_refund_tx {
+            self.create_transaction_event(
+                batch_id,
+                tx_nonce,
+                payment_token,
+                actual_bridged_amount,
+                required_fee,
+                refund_info.address.as_managed_buffer().clone(),
+                tx.to,
+            );
+
+            if let Some(call_data) = opt_call_data {
+                self.create_transaction_call_data_event(
+                    batch_id,
+                    tx_nonce,
+                    call_data.target,
+                    call_data.gas_limit,
+                    call_data.data,
+                );
+            }
+        } else {
+            self.create_refund_transaction_event(
+                batch_id,
+                tx_nonce,
+                payment_token,
+                actual_bridged_amount,
+                required_fee,
+                refund_info.initial_batch_id,
+                refund_info.initial_nonce,
+                refund_reason,
+            );
+        }
+    }
+
+    /// Checks a payment about to be bridged, keeps its fee and locks the rest.
+    /// Returns the bridged amount and the fee.
+    fn lock_bridged_payment<F>(
+        &self,
+        token_id: &TokenIdentifier,
+        amount: BigUint,
+        get_required_fee: F,
+    ) -> (BigUint, BigUint)
+    where
+        F: FnOnce(&TokenIdentifier) -> BigUint,
+    {
+        self.require_token_in_whitelist(token_id);
+        self.require_token_not_paused(token_id);
+
+        let required_fee = get_required_fee(token_id);
+        require!(
+            required_fee < amount,
+            "Transaction fees cost more than the entire bridged amount"
+        );
+
+        self.require_below_max_amount(token_id, &amount);
+
+        self.accumulated_transaction_fees(token_id)
+            .update(|fees| *fees += &required_fee);
+
+        let actual_bridged_amount = amount - &required_fee;
+        self.lock_bridged_tokens(token_id, &actual_bridged_amount);
+
+        (actual_bridged_amount, required_fee)
+    }
+
+    fn get_cached_required_fee(
+        &self,
+        token_id: &TokenIdentifier,
+        cached_token_ids: &mut ManagedVec<TokenIdentifier>,
+        cached_prices: &mut ManagedVec<BigUint>,
+    ) -> BigUint {
+        match cached_token_ids.iter().position(|id| *id == *token_id) {
+            Some(index) => (*cached_prices.get(index)).clone(),
+            None => {
+                let queried_fee = self.calculate_required_fee(token_id);
+                cached_token_ids.push(token_id.clone());
+                cached_prices.push(queried_fee.clone());
+
+                queried_fee
+            }
+        }
+    }
+
+    /// Mint/burn tokens are burned, the others are kept in the contract.
+    fn lock_bridged_tokens(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        if self.mint_burn_token(token_id).get() {
+            let burn_balances_mapper = self.burn_balances(token_id);
+            let mint_balances_mapper = self.mint_balances(token_id);
+            if !self.native_token(token_id).get() {
+                require!(
+                    mint_balances_mapper.get() >= &burn_balances_mapper.get() + amount,
+                    "Not enough minted tokens!"
+                );
+            }
+            let burn_executed = self.internal_burn(token_id, amount);
+            require!(burn_executed, "Cannot do the burn action!");
+            burn_balances_mapper.update(|burned| {
+                *burned += amount;
+            });
+        } else {
+            self.total_balances(token_id).update(|total| {
+                *total += amount;
+            });
+        }
+    }
+
+    /// Native EGLD is wrapped through the EGLD wrapper contract,
+    /// so it is bridged as the wrapped EGLD token.
+    fn get_bridged_payment(&self) -> (TokenIdentifier, BigUint) {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        if payment_token.is_esdt() {
+            return (payment_token.unwrap_esdt(), payment_amount);
+        }
+
+        let egld_wrapper_mapper = self.egld_wrapper_contract_address();
+        require!(
+            !egld_wrapper_mapper.is_empty(),
+            "Native EGLD is not accepted"
+        );
+
+        let wrapped_payment = self
+            .tx()
+            .to(egld_wrapper_mapper.get())
+            .typed(egld_wrapper_proxy::EgldEsdtSwapProxy)
+            .wrap_egld()
+            .egld(&payment_amount)
+            .returns(ReturnsResult)
+            .sync_call();
+
+        (wrapped_payment.token_identifier, wrapped_payment.amount)
+    }
+
     fn rebalance_for_refund(&self, token_id: &TokenIdentifier, amount: &BigUint) {
         let mintBurnToken = self.mint_burn_token(token_id).get();
         if !mintBurnToken {
@@ -568,6 +943,31 @@ pub trait EsdtSafe:
         }
     }
 
+    fn add_refund_transactions_to_batch(
+        &self,
+        new_transactions: &ManagedVec<Transaction<Self::Api>>,
+        original_tx_nonces: &ManagedVec<u64>,
+        fees: &ManagedVec<BigUint>,
+    ) {
+        let batch_ids = self.add_multiple_tx_to_batch(new_transactions);
+        for (i, tx) in new_transactions.iter().enumerate() {
+            let batch_id = batch_ids.get(i);
+            let original_tx_nonce = original_tx_nonces.get(i);
+
+            self.add_refund_transaction_event(batch_id, tx.nonce, original_tx_nonce);
+            self.create_refund_transaction_event(
+                batch_id,
+                tx.nonce,
+                tx.token_identifier,
+                tx.amount,
+                (*fees.get(i)).clone(),
+                tx.original_batch_id,
+                original_tx_nonce,
+                tx.refund_reason,
+            );
+        }
+    }
+
     fn mark_refund(&self, to: &ManagedAddress, token_id: &TokenIdentifier, amount: &BigUint) {
         self.refund_amount(to, token_id)
             .update(|refund| *refund += amount);
@@ -589,6 +989,16 @@ pub trait EsdtSafe:
         #[indexed] recipient: ManagedBuffer,
     );
 
+    #[event("createTransactionCallDataEvent")]
+    fn create_transaction_call_data_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] target: EthAddress<Self::Api>,
+        #[indexed] gas_limit: u64,
+        data: ManagedBuffer,
+    );
+
     #[event("createRefundTransactionEvent")]
     fn create_refund_transaction_event(
         &self,
@@ -599,6 +1009,7 @@ pub trait EsdtSafe:
         #[indexed] fee: BigUint,
         #[indexed] initial_batch_id: u64,
         #[indexed] initial_tx_id: u64,
+        #[indexed] refund_reason: RefundReason,
     );
 
     #[event("addRefundTransactionEvent")]
@@ -609,6 +1020,14 @@ pub trait EsdtSafe:
         #[indexed] original_tx_id: u64,
     );
 
+    #[event("unrefundableDust")]
+    fn unrefundable_dust_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] original_tx_id: u64,
+    );
+
     #[event("claimRefundTransactionEvent")]
     fn claim_refund_transaction_event(
         &self,
@@ -636,6 +1055,11 @@ pub trait EsdtSafe:
     #[storage_mapper("refundFeesForEthereum")]
     fn refund_fees_for_ethereum(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
+    /// Refunds that were too small to cover the fees
+    #[view(getUnrefundableDust)]
+    #[storage_mapper("unrefundableDust")]
+    fn unrefundable_dust(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
     #[storage_mapper("refundAmount")]
     fn refund_amount(
         &self,
@@ -650,4 +1074,12 @@ pub trait EsdtSafe:
     #[view(getBridgeProxyContractAddress)]
     #[storage_mapper("bridgeProxyContractAddress")]
     fn bridge_proxy_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getEgldWrapperContractAddress)]
+    #[storage_mapper("egldWrapperContractAddress")]
+    fn egld_wrapper_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getMaxOutboundCallGasLimit)]
+    #[storage_mapper("maxOutboundCallGasLimit")]
+    fn max_outbound_call_gas_limit(&self) -> SingleValueMapper<u64>;
 }
@@ -36,6 +36,16 @@ fn create_transaction_over_max_amount_rs() {
     world().run("scenarios/create_transaction_over_max_amount.scen.json");
 }
 
+#[test]
+fn create_transaction_with_call_data_rs() {
+    world().run("scenarios/create_transaction_with_call_data.scen.json");
+}
+
+#[test]
+fn create_transactions_rs() {
+    world().run("scenarios/create_transactions.scen.json");
+}
+
 #[test]
 fn distribute_fees_rs() {
     world().run("scenarios/distribute_fees.scen.json");
@@ -29,6 +29,16 @@ fn create_transaction_over_max_amount_go() {
     world().run("scenarios/create_transaction_over_max_amount.scen.json");
 }
 
+#[test]
+fn create_transaction_with_call_data_go() {
+    world().run("scenarios/create_transaction_with_call_data.scen.json");
+}
+
+#[test]
+fn create_transactions_go() {
+    world().run("scenarios/create_transactions.scen.json");
+}
+
 #[test]
 fn distribute_fees_go() {
     world().run("scenarios/distribute_fees.scen.json");
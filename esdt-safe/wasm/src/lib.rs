@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           51
+// Endpoints:                           76
 // Async Callback (empty):               1
-// Total number of exported functions:  54
+// Total number of exported functions:  79
 
 #![no_std]
 
@@ -22,19 +22,31 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         setTransactionBatchStatus => set_transaction_batch_status
         addRefundBatch => add_refund_batch
+        addUnfundedRefundBatch => add_unfunded_refund_batch
         createTransaction => create_transaction
+        createTransactionWithCallData => create_transaction_with_call_data
+        createTransactions => create_transactions
         claimRefund => claim_refund
         setBridgedTokensWrapperAddress => set_bridged_tokens_wrapper_contract_address
+        setEgldWrapperContractAddress => set_egld_wrapper_contract_address
+        setMaxOutboundCallGasLimit => set_max_outbound_call_gas_limit
         setBridgeProxyContractAddress => set_bridge_proxy_contract_address
         withdrawRefundFeesForEthereum => withdraw_refund_fees_for_ethereum
         withdrawTransactionFees => withdraw_transaction_fees
+        withdrawUnrefundableDust => withdraw_unrefundable_dust
+        recreditUnrefundableDust => recredit_unrefundable_dust
         computeTotalAmmountsFromIndex => compute_total_amounts_from_index
         getRefundAmounts => get_refund_amounts
         getTotalRefundAmounts => get_total_refund_amounts
         getRefundFeesForEthereum => get_refund_fees_for_ethereum
+        getTotalUnrefundableDust => get_total_unrefundable_dust
+        calculateRequiredFeeWithCallData => calculate_required_fee_with_call_data
         getTransactionFees => get_transaction_fees
+        getUnrefundableDust => unrefundable_dust
         getBridgedTokensWrapperAddress => bridged_tokens_wrapper_address
         getBridgeProxyContractAddress => bridge_proxy_contract_address
+        getEgldWrapperContractAddress => egld_wrapper_contract_address
+        getMaxOutboundCallGasLimit => max_outbound_call_gas_limit
         setFeeEstimatorContractAddress => set_fee_estimator_contract_address
         setEthTxGasLimit => set_eth_tx_gas_limit
         setDefaultPricePerGasUnit => set_default_price_per_gas_unit
@@ -63,6 +75,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrentTxBatch => get_current_tx_batch
         getFirstBatchAnyStatus => get_first_batch_any_status
         getBatch => get_batch
+        getCurrentTxBatchVersioned => get_current_tx_batch_versioned
+        getFirstBatchAnyStatusVersioned => get_first_batch_any_status_versioned
+        getBatchVersioned => get_batch_versioned
         getBatchStatus => get_batch_status
         getFirstBatchId => first_batch_id
         getLastBatchId => last_batch_id
@@ -71,6 +86,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
+        setOutflowThreshold => set_outflow_threshold
+        setOutflowWindowDuration => set_outflow_window_duration
+        resetCircuitBreaker => reset_circuit_breaker
+        getOutflowThreshold => outflow_threshold
+        getOutflowWindowDuration => outflow_window_duration
+        getOutflowInWindow => outflow_in_window
+        getCircuitBreakerTripReason => circuit_breaker_trip_reason
+        pauseToken => pause_token
+        unpauseToken => unpause_token
+        isTokenPaused => token_paused
     )
 }
 
@@ -1,22 +1,65 @@
 #![no_std]
 
-use multiversx_sc::{imports::*, storage::StorageKey};
+use multiversx_sc::{derive_imports::*, imports::*, storage::StorageKey};
 
+use bridge_proxy::config::{
+    MAX_GAS_LIMIT_FOR_SC_CALL_STORAGE_KEY, MIN_GAS_LIMIT_FOR_SC_CALL_STORAGE_KEY,
+};
+use circuit_breaker_module::{CircuitBreakerReason, CIRCUIT_BREAKER_TRIP_REASON_STORAGE_KEY};
 use eth_address::EthAddress;
-use transaction::{EthTransaction, PaymentsVec, Transaction, TxNonce};
+use transaction::{
+    call_data_validation::{validate_raw_call_data, CallDataError},
+    multi_call_data::MultiCallData,
+    refund_reason::RefundReason,
+    CallData, EthTransaction, PaymentsVec, Transaction, TxNonce,
+};
 
 pub mod bridge_proxy_contract_proxy;
 pub mod bridged_tokens_wrapper_proxy;
+pub mod egld_wrapper_proxy;
 pub mod esdt_safe_proxy;
 pub mod multi_transfer_proxy;
 
 const DEFAULT_MAX_TX_BATCH_SIZE: usize = 10;
 const DEFAULT_MAX_TX_BATCH_BLOCK_DURATION: u64 = u64::MAX;
+const DEFAULT_CLAIM_EXPIRY_DURATION: u64 = 100_800; // ~1 week, with 6 second blocks
 const CHAIN_SPECIFIC_TO_UNIVERSAL_TOKEN_MAPPING: &[u8] = b"chainSpecificToUniversalMapping";
+const TOKEN_WHITELIST_STORAGE_KEY: &[u8] = b"tokenWhitelist";
+const TOKEN_PAUSED_STORAGE_KEY: &[u8] = b"tokenPaused";
+const DEFAULT_CROSS_SHARD_TRANSFER_GAS_LIMIT: u64 = 500_000;
+const DEFAULT_CROSS_SHARD_TRANSFER_CALLBACK_GAS_LIMIT: u64 = 10_000_000; // 10 million
+const DEFAULT_MAX_CROSS_SHARD_TRANSFERS_PER_BATCH: usize = 5;
+const AUTO_EXECUTE_EXTRA_GAS: u64 = 40_000_000; // BridgeProxy execution overhead and callback
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QuarantinedTransfer<M: ManagedTypeApi> {
+    pub batch_id: u64,
+    pub eth_tx: EthTransaction<M>,
+    pub release_block: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ClaimableDeposit<M: ManagedTypeApi> {
+    pub batch_id: u64,
+    pub eth_tx: EthTransaction<M>,
+    pub payment: EsdtTokenPayment<M>,
+    pub expiry_block: u64,
+}
+
+#[derive(ManagedVecItem, Clone)]
+pub struct AutoExecution {
+    pub tx_id: usize,
+    pub gas_limit: u64,
+}
 
 #[multiversx_sc::contract]
 pub trait MultiTransferEsdt:
-    tx_batch_module::TxBatchModule + max_bridged_amount_module::MaxBridgedAmountModule
+    tx_batch_module::TxBatchModule
+    + max_bridged_amount_module::MaxBridgedAmountModule
+    + multiversx_sc_modules::pause::PauseModule
+    + token_pause_module::TokenPauseModule
 {
     #[init]
     fn init(&self) {
@@ -27,6 +70,14 @@ pub trait MultiTransferEsdt:
         // batch ID 0 is considered invalid
         self.first_batch_id().set_if_empty(1);
         self.last_batch_id().set_if_empty(1);
+        self.claim_expiry_duration()
+            .set_if_empty(DEFAULT_CLAIM_EXPIRY_DURATION);
+        self.cross_shard_transfer_gas_limit()
+            .set_if_empty(DEFAULT_CROSS_SHARD_TRANSFER_GAS_LIMIT);
+        self.cross_shard_transfer_callback_gas_limit()
+            .set_if_empty(DEFAULT_CROSS_SHARD_TRANSFER_CALLBACK_GAS_LIMIT);
+        self.max_cross_shard_transfers_per_batch()
+            .set_if_empty(DEFAULT_MAX_CROSS_SHARD_TRANSFERS_PER_BATCH);
     }
 
     #[upgrade]
@@ -38,6 +89,14 @@ pub trait MultiTransferEsdt:
         // batch ID 0 is considered invalid
         self.first_batch_id().set_if_empty(1);
         self.last_batch_id().set_if_empty(1);
+        self.claim_expiry_duration()
+            .set_if_empty(DEFAULT_CLAIM_EXPIRY_DURATION);
+        self.cross_shard_transfer_gas_limit()
+            .set_if_empty(DEFAULT_CROSS_SHARD_TRANSFER_GAS_LIMIT);
+        self.cross_shard_transfer_callback_gas_limit()
+            .set_if_empty(DEFAULT_CROSS_SHARD_TRANSFER_CALLBACK_GAS_LIMIT);
+        self.max_cross_shard_transfers_per_batch()
+            .set_if_empty(DEFAULT_MAX_CROSS_SHARD_TRANSFERS_PER_BATCH);
     }
 
     #[only_owner]
@@ -47,65 +106,54 @@ pub trait MultiTransferEsdt:
         batch_id: u64,
         transfers: MultiValueEncoded<EthTransaction<Self::Api>>,
     ) {
-        let mut valid_payments_list = ManagedVec::new();
-        let mut valid_tx_list = ManagedVec::new();
-        let mut refund_tx_list = ManagedVec::new();
-
-        let own_sc_address = self.blockchain().get_sc_address();
-        let sc_shard = self.blockchain().get_shard_of_address(&own_sc_address);
+        self.require_not_paused();
 
         let safe_address = self.esdt_safe_contract_address().get();
+        require!(
+            !self.is_safe_circuit_breaker_tripped(&safe_address),
+            "EsdtSafe circuit breaker tripped"
+        );
 
-        for eth_tx in transfers {
-            let is_success: bool = self
-                .tx()
-                .to(safe_address.clone())
-                .typed(esdt_safe_proxy::EsdtSafeProxy)
-                .get_tokens(&eth_tx.token_id, &eth_tx.amount)
-                .returns(ReturnsResult)
-                .sync_call();
-
-            require!(is_success, "Invalid token or amount");
+        self.process_transfers(batch_id, transfers);
+    }
 
-            let universal_token = self.get_universal_token(eth_tx.clone());
+    /// Delivers the transfers of a batch that were halted by a circuit breaker trip in the EsdtSafe.
+    /// The breaker has to be reset first.
+    #[only_owner]
+    #[endpoint(resumeHaltedTransfers)]
+    fn resume_halted_transfers(&self, batch_id: u64) {
+        self.require_not_paused();
 
-            let mut must_refund = false;
-            if eth_tx.to.is_zero() {
-                self.transfer_failed_invalid_destination(batch_id, eth_tx.tx_nonce);
-                must_refund = true;
-            } else if self.is_above_max_amount(&eth_tx.token_id, &eth_tx.amount) {
-                self.transfer_over_max_amount(batch_id, eth_tx.tx_nonce);
-                must_refund = true;
-            } else if self.is_account_same_shard_frozen(sc_shard, &eth_tx.to, &universal_token) {
-                self.transfer_failed_frozen_destination_account(batch_id, eth_tx.tx_nonce);
-                must_refund = true;
-            }
+        let safe_address = self.esdt_safe_contract_address().get();
+        require!(
+            !self.is_safe_circuit_breaker_tripped(&safe_address),
+            "EsdtSafe circuit breaker tripped"
+        );
 
-            if must_refund {
-                let refund_tx = self.convert_to_refund_tx(eth_tx);
-                refund_tx_list.push(refund_tx);
+        let mut halted_transfers_mapper = self.halted_transfers(batch_id);
+        require!(
+            !halted_transfers_mapper.is_empty(),
+            "No halted transfers for batch"
+        );
 
-                continue;
-            }
+        let mut transfers = MultiValueEncoded::new();
+        for eth_tx in halted_transfers_mapper.iter() {
+            transfers.push(eth_tx);
+        }
+        halted_transfers_mapper.clear();
+        self.halted_batch_ids().swap_remove(&batch_id);
 
-            // emit event before the actual transfer so we don't have to save the tx_nonces as well
-            self.transfer_performed_event(
-                batch_id,
-                eth_tx.from.clone(),
-                eth_tx.to.clone(),
-                eth_tx.token_id.clone(),
-                eth_tx.amount.clone(),
-                eth_tx.tx_nonce,
-            );
+        self.process_transfers(batch_id, transfers);
+    }
 
-            valid_tx_list.push(eth_tx.clone());
-            valid_payments_list.push(EsdtTokenPayment::new(eth_tx.token_id, 0, eth_tx.amount));
+    #[view(getHaltedTransfers)]
+    fn get_halted_transfers(&self, batch_id: u64) -> MultiValueEncoded<EthTransaction<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for eth_tx in self.halted_transfers(batch_id).iter() {
+            result.push(eth_tx);
         }
 
-        let payments_after_wrapping = self.wrap_tokens(valid_payments_list);
-        self.distribute_payments(valid_tx_list, payments_after_wrapping, batch_id);
-
-        self.add_multiple_tx_to_batch(&refund_tx_list);
+        result
     }
 
     #[only_owner]
@@ -113,37 +161,54 @@ pub trait MultiTransferEsdt:
     fn move_refund_batch_to_safe(&self) {
         let opt_current_batch = self.get_first_batch_any_status();
         match opt_current_batch {
-            OptionalValue::Some(current_batch) => {
+            OptionalValue::Some(_) => {
                 let first_batch_id = self.first_batch_id().get();
                 let mut first_batch = self.pending_batches(first_batch_id);
 
-                self.clear_first_batch(&mut first_batch);
-                let (_batch_id, all_tx_fields) = current_batch.into_tuple();
+                // the stored transactions are used instead of the fields,
+                // so the refund reason and the original batch ID are kept
                 let mut refund_batch = ManagedVec::new();
                 let mut refund_payments = ManagedVec::new();
+                let mut unfunded_refund_batch = ManagedVec::new();
 
-                for tx_fields in all_tx_fields {
-                    let (_, tx_nonce, _, _, token_identifier, amount) =
-                        tx_fields.clone().into_tuple();
+                for refund_tx in first_batch.iter() {
+                    let tx_nonce = refund_tx.nonce;
 
-                    if self.is_refund_valid(&token_identifier) {
-                        refund_batch.push(Transaction::from(tx_fields));
-                        refund_payments.push(EsdtTokenPayment::new(token_identifier, 0, amount));
+                    if self.unfunded_refund_txs().swap_remove(&tx_nonce) {
+                        unfunded_refund_batch.push(refund_tx);
+                    } else if self.is_refund_valid(&refund_tx.token_identifier) {
+                        refund_payments.push(EsdtTokenPayment::new(
+                            refund_tx.token_identifier.clone(),
+                            0,
+                            refund_tx.amount.clone(),
+                        ));
+                        refund_batch.push(refund_tx);
                     } else {
-                        self.unprocessed_refund_txs(tx_nonce)
-                            .set(Transaction::from(tx_fields));
+                        self.unprocessed_refund_txs(tx_nonce).set(refund_tx);
+                        self.unprocessed_refund_tx_nonces().insert(tx_nonce);
 
                         self.unprocessed_refund_txs_event(tx_nonce);
                     }
                 }
 
+                self.clear_first_batch(&mut first_batch);
+
                 let esdt_safe_addr = self.esdt_safe_contract_address().get();
-                self.tx()
-                    .to(esdt_safe_addr)
-                    .typed(esdt_safe_proxy::EsdtSafeProxy)
-                    .add_refund_batch(refund_batch)
-                    .payment(refund_payments)
-                    .sync_call();
+                if !refund_batch.is_empty() {
+                    self.tx()
+                        .to(&esdt_safe_addr)
+                        .typed(esdt_safe_proxy::EsdtSafeProxy)
+                        .add_refund_batch(refund_batch)
+                        .payment(refund_payments)
+                        .sync_call();
+                }
+                if !unfunded_refund_batch.is_empty() {
+                    self.tx()
+                        .to(&esdt_safe_addr)
+                        .typed(esdt_safe_proxy::EsdtSafeProxy)
+                        .add_unfunded_refund_batch(unfunded_refund_batch)
+                        .sync_call();
+                }
             }
             OptionalValue::None => {}
         }
@@ -165,6 +230,37 @@ pub trait MultiTransferEsdt:
         }
     }
 
+    #[only_owner]
+    #[endpoint(setEgldWrapperContractAddress)]
+    fn set_egld_wrapper_contract_address(&self, opt_new_address: OptionalValue<ManagedAddress>) {
+        match opt_new_address {
+            OptionalValue::Some(sc_addr) => {
+                require!(
+                    self.blockchain().is_smart_contract(&sc_addr),
+                    "Invalid EGLD wrapper contract address"
+                );
+
+                self.egld_wrapper_contract_address().set(&sc_addr);
+            }
+            OptionalValue::None => self.egld_wrapper_contract_address().clear(),
+        }
+    }
+
+    /// When enabled, the given wrapped EGLD token is unwrapped through the EGLD wrapper contract
+    /// and delivered as native EGLD. Only applies to user accounts,
+    /// smart contracts keep receiving the token.
+    #[only_owner]
+    #[endpoint(setNativeEgldDelivery)]
+    fn set_native_egld_delivery(&self, token_id: TokenIdentifier, enabled: bool) {
+        if enabled {
+            require!(
+                !self.egld_wrapper_contract_address().is_empty(),
+                "EGLD wrapper contract address not set"
+            );
+        }
+        self.native_egld_delivery(&token_id).set(enabled);
+    }
+
     #[only_owner]
     #[endpoint(setBridgeProxyContractAddress)]
     fn set_bridge_proxy_contract_address(&self, opt_new_address: OptionalValue<ManagedAddress>) {
@@ -190,6 +286,65 @@ pub trait MultiTransferEsdt:
         self.add_multiple_tx_to_batch(&refund_tx_list);
 
         self.unprocessed_refund_txs(tx_id).clear();
+        self.unprocessed_refund_tx_nonces().swap_remove(&tx_id);
+    }
+
+    /// Re-queues all the unprocessed refund transactions that are now valid,
+    /// e.g. after the EsdtSafe account was unfrozen for the token.
+    /// The others are kept for a later retry.
+    ///
+    /// Returns the number of re-queued transactions.
+    #[only_owner]
+    #[endpoint(retryUnprocessedRefundTxs)]
+    fn retry_unprocessed_refund_txs(&self) -> usize {
+        let tx_nonces: ManagedVec<TxNonce> = self.unprocessed_refund_tx_nonces().iter().collect();
+
+        let mut refund_tx_list = ManagedVec::new();
+        for tx_nonce in &tx_nonces {
+            let refund_tx = self.unprocessed_refund_txs(tx_nonce).get();
+            if !self.is_refund_valid(&refund_tx.token_identifier) {
+                continue;
+            }
+
+            self.unprocessed_refund_txs(tx_nonce).clear();
+            self.unprocessed_refund_tx_nonces().swap_remove(&tx_nonce);
+            refund_tx_list.push(refund_tx);
+        }
+
+        self.add_multiple_tx_to_batch(&refund_tx_list);
+
+        refund_tx_list.len()
+    }
+
+    /// Registers unprocessed refund transactions stored before their nonces were tracked,
+    /// so `retryUnprocessedRefundTxs` and `getUnprocessedRefundTxs` pick them up.
+    /// Nonces without a stored transaction are skipped.
+    ///
+    /// Returns the number of registered transactions.
+    #[only_owner]
+    #[endpoint(registerUnprocessedRefundTxs)]
+    fn register_unprocessed_refund_txs(&self, tx_nonces: MultiValueEncoded<TxNonce>) -> usize {
+        let mut nr_registered_txs = 0;
+        for tx_nonce in tx_nonces {
+            if self.unprocessed_refund_txs(tx_nonce).is_empty() {
+                continue;
+            }
+            if self.unprocessed_refund_tx_nonces().insert(tx_nonce) {
+                nr_registered_txs += 1;
+            }
+        }
+
+        nr_registered_txs
+    }
+
+    #[view(getUnprocessedRefundTxs)]
+    fn get_unprocessed_refund_txs(&self) -> MultiValueEncoded<Transaction<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for tx_nonce in self.unprocessed_refund_tx_nonces().iter() {
+            result.push(self.unprocessed_refund_txs(tx_nonce).get());
+        }
+
+        result
     }
 
     #[only_owner]
@@ -203,6 +358,218 @@ pub trait MultiTransferEsdt:
         }
     }
 
+    /// Transfers above the review threshold are not delivered right away,
+    /// but held in quarantine until the board releases or refunds them.
+    /// 0 disables the quarantine for the token.
+    #[only_owner]
+    #[endpoint(setReviewThreshold)]
+    fn set_review_threshold(&self, token_id: TokenIdentifier, threshold: BigUint) {
+        self.review_threshold(&token_id).set(&threshold);
+    }
+
+    /// Minimum number of blocks a transfer is held in quarantine before it can be released.
+    #[only_owner]
+    #[endpoint(setQuarantineDelay)]
+    fn set_quarantine_delay(&self, delay: u64) {
+        self.quarantine_delay().set(delay);
+    }
+
+    /// Number of blocks an undeliverable payment can be claimed for, before it can be refunded.
+    #[only_owner]
+    #[endpoint(setClaimExpiryDuration)]
+    fn set_claim_expiry_duration(&self, duration: u64) {
+        self.claim_expiry_duration().set(duration);
+    }
+
+    /// Gas given to each cross-shard delivery, and reserved for its callback,
+    /// which turns failed deliveries into refund transactions.
+    #[only_owner]
+    #[endpoint(setCrossShardTransferGasLimits)]
+    fn set_cross_shard_transfer_gas_limits(&self, gas_limit: u64, callback_gas_limit: u64) {
+        require!(
+            gas_limit > 0 && callback_gas_limit > 0,
+            "Gas limits must be more than 0"
+        );
+
+        self.cross_shard_transfer_gas_limit().set(gas_limit);
+        self.cross_shard_transfer_callback_gas_limit()
+            .set(callback_gas_limit);
+    }
+
+    /// Bounds the gas a batch reserves for cross-shard deliveries.
+    /// The ones over the limit are escrowed, for the recipients to claim.
+    #[only_owner]
+    #[endpoint(setMaxCrossShardTransfersPerBatch)]
+    fn set_max_cross_shard_transfers_per_batch(&self, max_transfers: usize) {
+        require!(
+            max_transfers > 0,
+            "Max cross-shard transfers per batch must be more than 0"
+        );
+
+        self.max_cross_shard_transfers_per_batch()
+            .set(max_transfers);
+    }
+
+    /// When enabled, smart contract calls deposited in the BridgeProxy are executed at the end of the batch,
+    /// if the gas left over allows it. Otherwise, they stay queued until someone calls `execute`.
+    #[only_owner]
+    #[endpoint(setAutoExecute)]
+    fn set_auto_execute(&self, enabled: bool) {
+        self.auto_execute_enabled().set(enabled);
+    }
+
+    #[only_owner]
+    #[endpoint(releaseQuarantinedTransfer)]
+    fn release_quarantined_transfer(&self, tx_nonce: TxNonce) {
+        let quarantined_transfer = self.take_quarantined_transfer(tx_nonce);
+        require!(
+            self.blockchain().get_block_nonce() >= quarantined_transfer.release_block,
+            "Quarantine period not over"
+        );
+
+        let batch_id = quarantined_transfer.batch_id;
+        let eth_tx = quarantined_transfer.eth_tx;
+        self.transfer_performed_event(
+            batch_id,
+            eth_tx.from.clone(),
+            eth_tx.to.clone(),
+            eth_tx.token_id.clone(),
+            eth_tx.amount.clone(),
+            eth_tx.tx_nonce,
+        );
+
+        let payments = ManagedVec::from_single_item(EsdtTokenPayment::new(
+            eth_tx.token_id.clone(),
+            0,
+            eth_tx.amount.clone(),
+        ));
+        let payments_after_wrapping = self.wrap_tokens(payments);
+        let auto_executions = self.distribute_payments(
+            ManagedVec::from_single_item(eth_tx),
+            payments_after_wrapping,
+            batch_id,
+        );
+        self.auto_execute_transactions(auto_executions);
+    }
+
+    /// Quarantined transfers can be refunded at any time, without waiting for the delay.
+    #[only_owner]
+    #[endpoint(refundQuarantinedTransfer)]
+    fn refund_quarantined_transfer(&self, tx_nonce: TxNonce) {
+        let quarantined_transfer = self.take_quarantined_transfer(tx_nonce);
+        self.quarantined_transfer_refunded_event(quarantined_transfer.batch_id, tx_nonce);
+
+        let refund_tx = self.convert_to_refund_tx(
+            quarantined_transfer.batch_id,
+            quarantined_transfer.eth_tx,
+            RefundReason::QuarantineRefunded,
+        );
+        self.add_multiple_tx_to_batch(&ManagedVec::from_single_item(refund_tx));
+    }
+
+    #[view(isTransferQuarantined)]
+    fn is_transfer_quarantined(&self, tx_nonce: TxNonce) -> bool {
+        self.quarantined_transfers().contains_key(&tx_nonce)
+    }
+
+    /// Payments that could not be delivered directly (e.g. to non-payable SCs)
+    /// are held in escrow under the receiver's address.
+    /// The receiver can claim all of them at once, as long as they are not expired.
+    #[endpoint(claimBridgedTokens)]
+    fn claim_bridged_tokens(&self) {
+        let caller = self.blockchain().get_caller();
+        let current_block = self.blockchain().get_block_nonce();
+        let tx_nonces: ManagedVec<TxNonce> =
+            self.claimable_deposit_nonces(&caller).iter().collect();
+
+        let mut payments = ManagedVec::new();
+        for tx_nonce in &tx_nonces {
+            let deposit = match self.claimable_deposits().get(&tx_nonce) {
+                Some(deposit) => deposit,
+                None => continue,
+            };
+            if current_block >= deposit.expiry_block {
+                continue;
+            }
+
+            let _ = self.claimable_deposits().remove(&tx_nonce);
+            self.claimable_deposit_nonces(&caller)
+                .swap_remove(&tx_nonce);
+            self.bridged_tokens_claimed_event(deposit.batch_id, tx_nonce, &caller);
+
+            payments.push(deposit.payment);
+        }
+
+        require!(!payments.is_empty(), "Nothing to claim");
+
+        self.tx().to(&caller).payment(&payments).transfer();
+    }
+
+    /// Expired deposits are converted into refund transactions. Can be called by anyone.
+    #[endpoint(refundExpiredDeposit)]
+    fn refund_expired_deposit(&self, tx_nonce: TxNonce) {
+        let deposit = match self.claimable_deposits().get(&tx_nonce) {
+            Some(deposit) => deposit,
+            None => sc_panic!("No claimable deposit for transaction"),
+        };
+        require!(
+            self.blockchain().get_block_nonce() >= deposit.expiry_block,
+            "Deposit has not expired yet"
+        );
+
+        let _ = self.claimable_deposits().remove(&tx_nonce);
+        self.claimable_deposit_nonces(&deposit.eth_tx.to)
+            .swap_remove(&tx_nonce);
+        self.expired_deposit_refunded_event(deposit.batch_id, tx_nonce);
+
+        self.unwrap_tokens(&deposit.eth_tx.token_id, deposit.payment);
+
+        let refund_tx =
+            self.convert_to_refund_tx(deposit.batch_id, deposit.eth_tx, RefundReason::ClaimExpired);
+        self.add_multiple_tx_to_batch(&ManagedVec::from_single_item(refund_tx));
+    }
+
+    #[view(getClaimableDeposits)]
+    fn get_claimable_deposits(
+        &self,
+        receiver: ManagedAddress,
+    ) -> MultiValueEncoded<ClaimableDeposit<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for tx_nonce in self.claimable_deposit_nonces(&receiver).iter() {
+            if let Some(deposit) = self.claimable_deposits().get(&tx_nonce) {
+                result.push(deposit);
+            }
+        }
+
+        result
+    }
+
+    /// Cross-shard deliveries can not be checked beforehand (e.g. for frozen accounts),
+    /// so failed ones are turned into refund transactions once the tokens come back.
+    #[promises_callback]
+    fn cross_shard_transfer_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+        batch_id: u64,
+        tx_nonce: TxNonce,
+        payment: EsdtTokenPayment,
+    ) {
+        let eth_tx = match self.pending_cross_shard_transfers().remove(&tx_nonce) {
+            Some(eth_tx) => eth_tx,
+            None => return,
+        };
+        if result.is_ok() {
+            return;
+        }
+
+        self.transfer_failed_frozen_destination_account(batch_id, tx_nonce);
+        self.unwrap_tokens(&eth_tx.token_id, payment);
+
+        let refund_tx =
+            self.convert_to_refund_tx(batch_id, eth_tx, RefundReason::FrozenDestinationAccount);
+        self.add_multiple_tx_to_batch(&ManagedVec::from_single_item(refund_tx));
+    }
+
     // private
 
     fn is_refund_valid(&self, token_id: &TokenIdentifier) -> bool {
@@ -216,6 +583,24 @@ pub trait MultiTransferEsdt:
         return true;
     }
 
+    /// The BridgedTokensWrapper rejects paused tokens, which would revert the whole batch.
+    fn is_token_paused_in_wrapper(&self, token_id: &TokenIdentifier) -> bool {
+        if self.wrapping_contract_address().is_empty() {
+            return false;
+        }
+
+        let mut storage_key = StorageKey::new(TOKEN_PAUSED_STORAGE_KEY);
+        storage_key.append_item(token_id);
+
+        let token_paused_mapper: SingleValueMapper<bool, ManagedAddress> =
+            SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+                self.wrapping_contract_address().get(),
+                storage_key,
+            );
+
+        token_paused_mapper.get()
+    }
+
     fn get_universal_token(&self, eth_tx: EthTransaction<Self::Api>) -> TokenIdentifier {
         let mut storage_key = StorageKey::new(CHAIN_SPECIFIC_TO_UNIVERSAL_TOKEN_MAPPING);
         storage_key.append_item(&eth_tx.token_id);
@@ -234,7 +619,245 @@ pub trait MultiTransferEsdt:
         }
     }
 
-    fn convert_to_refund_tx(&self, eth_tx: EthTransaction<Self::Api>) -> Transaction<Self::Api> {
+    fn is_token_whitelisted_in_safe(
+        &self,
+        safe_address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> bool {
+        let token_whitelist_mapper: UnorderedSetMapper<TokenIdentifier, ManagedAddress> =
+            UnorderedSetMapper::<_, _, ManagedAddress>::new_from_address(
+                safe_address.clone(),
+                StorageKey::new(TOKEN_WHITELIST_STORAGE_KEY),
+            );
+
+        token_whitelist_mapper.contains(token_id)
+    }
+
+    fn is_above_review_threshold(&self, token_id: &TokenIdentifier, amount: &BigUint) -> bool {
+        let review_threshold = self.review_threshold(token_id).get();
+        review_threshold > 0 && amount > &review_threshold
+    }
+
+    fn process_transfers(
+        &self,
+        batch_id: u64,
+        transfers: MultiValueEncoded<EthTransaction<Self::Api>>,
+    ) {
+        let mut valid_payments_list = ManagedVec::new();
+        let mut valid_tx_list = ManagedVec::new();
+        let mut refund_tx_list = ManagedVec::new();
+
+        let own_sc_address = self.blockchain().get_sc_address();
+        let sc_shard = self.blockchain().get_shard_of_address(&own_sc_address);
+        let safe_address = self.esdt_safe_contract_address().get();
+
+        let mut opt_first_halted_tx = None;
+        let mut transfers_iter = transfers.into_iter();
+        for eth_tx in transfers_iter.by_ref() {
+            // the tokens are left in the EsdtSafe, so the refund will not be funded
+            if self.is_token_paused(&eth_tx.token_id)
+                || self.is_token_paused_in_wrapper(&eth_tx.token_id)
+            {
+                self.transfer_failed_token_paused(batch_id, eth_tx.tx_nonce);
+                self.add_unfunded_refund_tx(
+                    batch_id,
+                    eth_tx,
+                    RefundReason::TokenPaused,
+                    &mut refund_tx_list,
+                );
+
+                continue;
+            }
+
+            if !self.is_token_whitelisted_in_safe(&safe_address, &eth_tx.token_id) {
+                self.transfer_failed_invalid_token(batch_id, eth_tx.tx_nonce);
+                self.add_unfunded_refund_tx(
+                    batch_id,
+                    eth_tx,
+                    RefundReason::InvalidToken,
+                    &mut refund_tx_list,
+                );
+
+                continue;
+            }
+
+            // an earlier transfer of this batch may have tripped the breaker
+            if self.is_safe_circuit_breaker_tripped(&safe_address) {
+                opt_first_halted_tx = Some(eth_tx);
+                break;
+            }
+
+            let is_success: bool = self
+                .tx()
+                .to(safe_address.clone())
+                .typed(esdt_safe_proxy::EsdtSafeProxy)
+                .get_tokens(&eth_tx.token_id, &eth_tx.amount)
+                .returns(ReturnsResult)
+                .sync_call();
+
+            if !is_success && self.is_safe_circuit_breaker_tripped(&safe_address) {
+                opt_first_halted_tx = Some(eth_tx);
+                break;
+            }
+
+            // e.g. not enough liquidity or missing mint role, nothing was taken from the EsdtSafe
+            if !is_success {
+                self.transfer_failed_invalid_token(batch_id, eth_tx.tx_nonce);
+                self.add_unfunded_refund_tx(
+                    batch_id,
+                    eth_tx,
+                    RefundReason::InvalidToken,
+                    &mut refund_tx_list,
+                );
+
+                continue;
+            }
+
+            let universal_token = self.get_universal_token(eth_tx.clone());
+
+            let mut refund_reason = RefundReason::None;
+            if eth_tx.to.is_zero() {
+                self.transfer_failed_invalid_destination(batch_id, eth_tx.tx_nonce);
+                refund_reason = RefundReason::InvalidDestination;
+            } else if self.is_above_max_amount(&eth_tx.token_id, &eth_tx.amount) {
+                self.transfer_over_max_amount(batch_id, eth_tx.tx_nonce);
+                refund_reason = RefundReason::OverMaxAmount;
+            } else if self.is_account_same_shard_frozen(sc_shard, &eth_tx.to, &universal_token) {
+                self.transfer_failed_frozen_destination_account(batch_id, eth_tx.tx_nonce);
+                refund_reason = RefundReason::FrozenDestinationAccount;
+            } else if let Err(error) = self.validate_sc_call_data(&eth_tx) {
+                self.transfer_failed_invalid_call_data(batch_id, eth_tx.tx_nonce, error);
+                refund_reason = RefundReason::InvalidCallData;
+            }
+
+            if refund_reason != RefundReason::None {
+                let refund_tx = self.convert_to_refund_tx(batch_id, eth_tx, refund_reason);
+                refund_tx_list.push(refund_tx);
+
+                continue;
+            }
+
+            if self.is_above_review_threshold(&eth_tx.token_id, &eth_tx.amount) {
+                self.quarantine_transfer(batch_id, eth_tx);
+
+                continue;
+            }
+
+            // emit event before the actual transfer so we don't have to save the tx_nonces as well
+            self.transfer_performed_event(
+                batch_id,
+                eth_tx.from.clone(),
+                eth_tx.to.clone(),
+                eth_tx.token_id.clone(),
+                eth_tx.amount.clone(),
+                eth_tx.tx_nonce,
+            );
+
+            valid_tx_list.push(eth_tx.clone());
+            valid_payments_list.push(EsdtTokenPayment::new(eth_tx.token_id, 0, eth_tx.amount));
+        }
+
+        // reverting would also revert the trip, so the rest of the batch is kept
+        // until the breaker is reset, instead of being refunded
+        if let Some(first_halted_tx) = opt_first_halted_tx {
+            let mut halted_transfers_mapper = self.halted_transfers(batch_id);
+            halted_transfers_mapper.push(&first_halted_tx);
+            for eth_tx in transfers_iter {
+                halted_transfers_mapper.push(&eth_tx);
+            }
+            self.halted_batch_ids().insert(batch_id);
+            self.transfers_halted_event(batch_id, halted_transfers_mapper.len());
+        }
+
+        let payments_after_wrapping = self.wrap_tokens(valid_payments_list);
+        let auto_executions =
+            self.distribute_payments(valid_tx_list, payments_after_wrapping, batch_id);
+
+        self.add_multiple_tx_to_batch(&refund_tx_list);
+        self.auto_execute_transactions(auto_executions);
+    }
+
+    fn quarantine_transfer(&self, batch_id: u64, eth_tx: EthTransaction<Self::Api>) {
+        let tx_nonce = eth_tx.tx_nonce;
+        let release_block = self.blockchain().get_block_nonce() + self.quarantine_delay().get();
+        let _ = self.quarantined_transfers().insert(
+            tx_nonce,
+            QuarantinedTransfer {
+                batch_id,
+                eth_tx,
+                release_block,
+            },
+        );
+
+        self.transfer_quarantined_event(batch_id, tx_nonce);
+    }
+
+    fn take_quarantined_transfer(&self, tx_nonce: TxNonce) -> QuarantinedTransfer<Self::Api> {
+        match self.quarantined_transfers().remove(&tx_nonce) {
+            Some(quarantined_transfer) => quarantined_transfer,
+            None => sc_panic!("Transfer is not quarantined"),
+        }
+    }
+
+    /// Call data is only used for smart contract receivers, through the BridgeProxy.
+    /// The gas bounds are read from the BridgeProxy, so both contracts classify the call data the same way.
+    /// The BridgeProxy itself is never a valid receiver.
+    fn validate_sc_call_data(
+        &self,
+        eth_tx: &EthTransaction<Self::Api>,
+    ) -> Result<(), CallDataError> {
+        let bridge_proxy_addr = self.bridge_proxy_contract_address().get();
+        if bridge_proxy_addr.is_zero() || !self.blockchain().is_smart_contract(&eth_tx.to) {
+            return Ok(());
+        }
+        if eth_tx.to == bridge_proxy_addr {
+            return Err(CallDataError::InvalidTarget);
+        }
+        if eth_tx.call_data.is_none() {
+            return Ok(());
+        }
+
+        let min_gas_limit_mapper: SingleValueMapper<u64, ManagedAddress> =
+            SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+                bridge_proxy_addr.clone(),
+                StorageKey::new(MIN_GAS_LIMIT_FOR_SC_CALL_STORAGE_KEY),
+            );
+        let max_gas_limit_mapper: SingleValueMapper<u64, ManagedAddress> =
+            SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+                bridge_proxy_addr.clone(),
+                StorageKey::new(MAX_GAS_LIMIT_FOR_SC_CALL_STORAGE_KEY),
+            );
+        let max_gas_limit = if max_gas_limit_mapper.is_empty() {
+            u64::MAX
+        } else {
+            max_gas_limit_mapper.get()
+        };
+
+        let raw_call_data = unsafe { eth_tx.call_data.clone().unwrap_no_check() };
+        validate_raw_call_data(
+            &raw_call_data,
+            &bridge_proxy_addr,
+            min_gas_limit_mapper.get(),
+            max_gas_limit,
+        )
+    }
+
+    fn is_safe_circuit_breaker_tripped(&self, safe_address: &ManagedAddress) -> bool {
+        let trip_reason_mapper: SingleValueMapper<CircuitBreakerReason, ManagedAddress> =
+            SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+                safe_address.clone(),
+                StorageKey::new(CIRCUIT_BREAKER_TRIP_REASON_STORAGE_KEY),
+            );
+
+        trip_reason_mapper.get() != CircuitBreakerReason::None
+    }
+
+    fn convert_to_refund_tx(
+        &self,
+        batch_id: u64,
+        eth_tx: EthTransaction<Self::Api>,
+        refund_reason: RefundReason,
+    ) -> Transaction<Self::Api> {
         Transaction {
             block_nonce: self.blockchain().get_block_nonce(),
             nonce: eth_tx.tx_nonce,
@@ -243,9 +866,25 @@ pub trait MultiTransferEsdt:
             token_identifier: eth_tx.token_id,
             amount: eth_tx.amount,
             is_refund_tx: true,
+            refund_reason,
+            original_batch_id: batch_id,
+            call_data: ManagedOption::none(),
         }
     }
 
+    fn add_unfunded_refund_tx(
+        &self,
+        batch_id: u64,
+        eth_tx: EthTransaction<Self::Api>,
+        refund_reason: RefundReason,
+        refund_tx_list: &mut ManagedVec<Transaction<Self::Api>>,
+    ) {
+        self.unfunded_refund_txs().insert(eth_tx.tx_nonce);
+
+        let refund_tx = self.convert_to_refund_tx(batch_id, eth_tx, refund_reason);
+        refund_tx_list.push(refund_tx);
+    }
+
     fn is_local_role_set(&self, token_id: &TokenIdentifier, role: &EsdtLocalRole) -> bool {
         let roles = self.blockchain().get_esdt_local_roles(token_id);
 
@@ -284,21 +923,119 @@ pub trait MultiTransferEsdt:
             .sync_call()
     }
 
+    fn unwrap_tokens(&self, requested_token: &TokenIdentifier, payment: EsdtTokenPayment) {
+        if requested_token == &payment.token_identifier {
+            return;
+        }
+
+        let bridged_tokens_wrapper_addr = self.wrapping_contract_address().get();
+        self.tx()
+            .to(bridged_tokens_wrapper_addr)
+            .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+            .unwrap_token(requested_token)
+            .single_esdt(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            )
+            .sync_call();
+    }
+
+    fn is_payable_by_sc(&self, sc_shard: u32, dest_address: &ManagedAddress) -> bool {
+        // the code metadata is only available for same shard accounts
+        if self.blockchain().get_shard_of_address(dest_address) != sc_shard {
+            return true;
+        }
+
+        let code_metadata = self.blockchain().get_code_metadata(dest_address);
+        code_metadata.is_payable() || code_metadata.is_payable_by_sc()
+    }
+
+    fn escrow_deposit(
+        &self,
+        batch_id: u64,
+        eth_tx: &EthTransaction<Self::Api>,
+        payment: &EsdtTokenPayment,
+    ) {
+        let tx_nonce = eth_tx.tx_nonce;
+        let expiry_block = self.blockchain().get_block_nonce() + self.claim_expiry_duration().get();
+        let _ = self.claimable_deposits().insert(
+            tx_nonce,
+            ClaimableDeposit {
+                batch_id,
+                eth_tx: eth_tx.clone(),
+                payment: payment.clone(),
+                expiry_block,
+            },
+        );
+        self.claimable_deposit_nonces(&eth_tx.to).insert(tx_nonce);
+
+        self.deposit_escrowed_event(batch_id, tx_nonce, &eth_tx.to);
+    }
+
+    fn transfer_cross_shard(
+        &self,
+        batch_id: u64,
+        eth_tx: &EthTransaction<Self::Api>,
+        payment: &EsdtTokenPayment,
+    ) {
+        let tx_nonce = eth_tx.tx_nonce;
+        let _ = self
+            .pending_cross_shard_transfers()
+            .insert(tx_nonce, eth_tx.clone());
+
+        self.tx()
+            .to(&eth_tx.to)
+            .raw_call(ManagedBuffer::new())
+            .gas(self.cross_shard_transfer_gas_limit().get())
+            .callback(self.callbacks().cross_shard_transfer_callback(
+                batch_id,
+                tx_nonce,
+                payment.clone(),
+            ))
+            .with_extra_gas_for_callback(self.cross_shard_transfer_callback_gas_limit().get())
+            .with_esdt_transfer(payment.clone())
+            .register_promise();
+    }
+
     fn distribute_payments(
         &self,
         transfers: ManagedVec<EthTransaction<Self::Api>>,
         payments: PaymentsVec<Self::Api>,
         batch_id: u64,
-    ) {
+    ) -> ManagedVec<AutoExecution> {
+        let mut auto_executions = ManagedVec::new();
         let bridge_proxy_addr = self.bridge_proxy_contract_address().get();
+        let own_sc_address = self.blockchain().get_sc_address();
+        let sc_shard = self.blockchain().get_shard_of_address(&own_sc_address);
+        let max_cross_shard_transfers = self.max_cross_shard_transfers_per_batch().get();
+        let mut nr_cross_shard_transfers = 0;
         for (eth_tx, p) in transfers.iter().zip(payments.iter()) {
-            if self.blockchain().is_smart_contract(&eth_tx.to) {
-                self.tx()
+            let is_smart_contract = self.blockchain().is_smart_contract(&eth_tx.to);
+            if !is_smart_contract && self.native_egld_delivery(&p.token_identifier).get() {
+                self.deliver_native_egld(&eth_tx.to, &p);
+            } else if is_smart_contract && !bridge_proxy_addr.is_zero() {
+                let tx_id = self
+                    .tx()
                     .to(bridge_proxy_addr.clone())
                     .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
                     .deposit(&eth_tx, batch_id)
                     .single_esdt(&p.token_identifier, 0, &p.amount)
+                    .returns(ReturnsResult)
                     .sync_call();
+
+                if let Some(gas_limit) = self.get_auto_execute_gas_limit(&eth_tx) {
+                    auto_executions.push(AutoExecution { tx_id, gas_limit });
+                }
+            } else if is_smart_contract && !self.is_payable_by_sc(sc_shard, &eth_tx.to) {
+                self.escrow_deposit(batch_id, &eth_tx, &p);
+            } else if self.blockchain().get_shard_of_address(&eth_tx.to) != sc_shard {
+                if nr_cross_shard_transfers < max_cross_shard_transfers {
+                    nr_cross_shard_transfers += 1;
+                    self.transfer_cross_shard(batch_id, &eth_tx, &p);
+                } else {
+                    self.escrow_deposit(batch_id, &eth_tx, &p);
+                }
             } else {
                 self.tx()
                     .to(&eth_tx.to)
@@ -306,6 +1043,70 @@ pub trait MultiTransferEsdt:
                     .transfer();
             }
         }
+
+        auto_executions
+    }
+
+    /// Runs once the rest of the batch is done, so executions only use the gas left over by it.
+    /// Transactions that do not fit stay queued in the BridgeProxy.
+    fn auto_execute_transactions(&self, auto_executions: ManagedVec<AutoExecution>) {
+        if auto_executions.is_empty() {
+            return;
+        }
+
+        let bridge_proxy_addr = self.bridge_proxy_contract_address().get();
+        for auto_execution in auto_executions.iter() {
+            let gas_left = self.blockchain().get_gas_left();
+            if auto_execution.gas_limit >= gas_left.saturating_sub(AUTO_EXECUTE_EXTRA_GAS) {
+                continue;
+            }
+
+            self.tx()
+                .to(bridge_proxy_addr.clone())
+                .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+                .execute(auto_execution.tx_id)
+                .sync_call();
+        }
+    }
+
+    /// EGLD transfers to user accounts can not fail, so they need no refund handling,
+    /// not even when the receiver is in another shard.
+    fn deliver_native_egld(&self, to: &ManagedAddress, payment: &EsdtTokenPayment) {
+        let back_transfers = self
+            .tx()
+            .to(self.egld_wrapper_contract_address().get())
+            .typed(egld_wrapper_proxy::EgldEsdtSwapProxy)
+            .unwrap_egld()
+            .single_esdt(&payment.token_identifier, 0, &payment.amount)
+            .returns(ReturnsBackTransfers)
+            .sync_call();
+
+        self.tx()
+            .to(to)
+            .egld(&back_transfers.total_egld_amount)
+            .transfer();
+    }
+
+    fn get_auto_execute_gas_limit(&self, eth_tx: &EthTransaction<Self::Api>) -> Option<u64> {
+        if !self.auto_execute_enabled().get() || eth_tx.call_data.is_none() {
+            return None;
+        }
+
+        let call_data_buffer = unsafe { eth_tx.call_data.clone().unwrap_no_check() };
+        let gas_limit = if MultiCallData::is_multi_call(&call_data_buffer) {
+            let Ok(multi_call_data) = MultiCallData::<Self::Api>::top_decode(call_data_buffer)
+            else {
+                return None;
+            };
+            multi_call_data.total_gas_limit()
+        } else {
+            let Ok(call_data) = CallData::<Self::Api>::top_decode(call_data_buffer) else {
+                return None;
+            };
+            call_data.gas_limit
+        };
+
+        Some(gas_limit)
     }
 
     // storage
@@ -313,6 +1114,14 @@ pub trait MultiTransferEsdt:
     #[storage_mapper("wrappingContractAddress")]
     fn wrapping_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getEgldWrapperContractAddress)]
+    #[storage_mapper("egldWrapperContractAddress")]
+    fn egld_wrapper_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(isNativeEgldDeliveryEnabled)]
+    #[storage_mapper("nativeEgldDelivery")]
+    fn native_egld_delivery(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
     #[view(getBridgeProxyContractAddress)]
     #[storage_mapper("bridgeProxyContractAddress")]
     fn bridge_proxy_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -321,9 +1130,68 @@ pub trait MultiTransferEsdt:
     #[storage_mapper("esdtSafeContractAddress")]
     fn esdt_safe_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getUnprocessedRefundTx)]
     #[storage_mapper("unprocessedRefundTxs")]
     fn unprocessed_refund_txs(&self, tx_id: u64) -> SingleValueMapper<Transaction<Self::Api>>;
 
+    #[storage_mapper("haltedTransfers")]
+    fn halted_transfers(&self, batch_id: u64) -> VecMapper<EthTransaction<Self::Api>>;
+
+    #[view(getHaltedBatchIds)]
+    #[storage_mapper("haltedBatchIds")]
+    fn halted_batch_ids(&self) -> UnorderedSetMapper<u64>;
+
+    #[view(getUnprocessedRefundTxNonces)]
+    #[storage_mapper("unprocessedRefundTxNonces")]
+    fn unprocessed_refund_tx_nonces(&self) -> UnorderedSetMapper<TxNonce>;
+
+    /// Refund transactions for which the tokens were never taken from the EsdtSafe,
+    /// i.e. paused tokens and failed `getTokens` calls
+    #[storage_mapper("unfundedRefundTxs")]
+    fn unfunded_refund_txs(&self) -> UnorderedSetMapper<TxNonce>;
+
+    #[view(getReviewThreshold)]
+    #[storage_mapper("reviewThreshold")]
+    fn review_threshold(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getQuarantineDelay)]
+    #[storage_mapper("quarantineDelay")]
+    fn quarantine_delay(&self) -> SingleValueMapper<u64>;
+
+    #[view(getQuarantinedTransfers)]
+    #[storage_mapper("quarantinedTransfers")]
+    fn quarantined_transfers(&self) -> MapMapper<TxNonce, QuarantinedTransfer<Self::Api>>;
+
+    #[view(getPendingCrossShardTransfers)]
+    #[storage_mapper("pendingCrossShardTransfers")]
+    fn pending_cross_shard_transfers(&self) -> MapMapper<TxNonce, EthTransaction<Self::Api>>;
+
+    #[view(getCrossShardTransferGasLimit)]
+    #[storage_mapper("crossShardTransferGasLimit")]
+    fn cross_shard_transfer_gas_limit(&self) -> SingleValueMapper<u64>;
+
+    #[view(getCrossShardTransferCallbackGasLimit)]
+    #[storage_mapper("crossShardTransferCallbackGasLimit")]
+    fn cross_shard_transfer_callback_gas_limit(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxCrossShardTransfersPerBatch)]
+    #[storage_mapper("maxCrossShardTransfersPerBatch")]
+    fn max_cross_shard_transfers_per_batch(&self) -> SingleValueMapper<usize>;
+
+    #[view(getClaimExpiryDuration)]
+    #[storage_mapper("claimExpiryDuration")]
+    fn claim_expiry_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("claimableDeposits")]
+    fn claimable_deposits(&self) -> MapMapper<TxNonce, ClaimableDeposit<Self::Api>>;
+
+    #[storage_mapper("claimableDepositNonces")]
+    fn claimable_deposit_nonces(&self, receiver: &ManagedAddress) -> UnorderedSetMapper<TxNonce>;
+
+    #[view(isAutoExecuteEnabled)]
+    #[storage_mapper("autoExecuteEnabled")]
+    fn auto_execute_enabled(&self) -> SingleValueMapper<bool>;
+
     // events
 
     #[event("transferPerformedEvent")]
@@ -350,9 +1218,48 @@ pub trait MultiTransferEsdt:
         #[indexed] tx_id: u64,
     );
 
+    #[event("transferFailedTokenPaused")]
+    fn transfer_failed_token_paused(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("transferFailedInvalidCallData")]
+    fn transfer_failed_invalid_call_data(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] error: CallDataError,
+    );
+
     #[event("transferOverMaxAmount")]
     fn transfer_over_max_amount(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
 
     #[event("unprocessedRefundTxs")]
     fn unprocessed_refund_txs_event(&self, #[indexed] tx_id: u64);
+
+    #[event("transfersHalted")]
+    fn transfers_halted_event(&self, #[indexed] batch_id: u64, nr_halted_transfers: usize);
+
+    #[event("transferQuarantined")]
+    fn transfer_quarantined_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("quarantinedTransferRefunded")]
+    fn quarantined_transfer_refunded_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("depositEscrowed")]
+    fn deposit_escrowed_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] receiver: &ManagedAddress,
+    );
+
+    #[event("bridgedTokensClaimed")]
+    fn bridged_tokens_claimed_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] receiver: &ManagedAddress,
+    );
+
+    #[event("expiredDepositRefunded")]
+    fn expired_deposit_refunded_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
 }
@@ -8,21 +8,23 @@ use bridge_proxy::{
 use bridged_tokens_wrapper::ProxyTrait as _;
 use esdt_safe::{EsdtSafe, ProxyTrait as _};
 use multi_transfer_esdt::{
-    bridged_tokens_wrapper_proxy, esdt_safe_proxy, multi_transfer_proxy, ProxyTrait as _,
+    bridged_tokens_wrapper_proxy, egld_wrapper_proxy, esdt_safe_proxy, multi_transfer_proxy,
+    MultiTransferEsdt, ProxyTrait as _,
 };
 
 use multiversx_sc::{
     api::{HandleConstraints, ManagedTypeApi},
     codec::{
-        multi_types::{MultiValueVec, OptionalValue},
+        multi_types::{MultiValue2, MultiValueVec, OptionalValue},
         Empty, TopEncode,
     },
     contract_base::ManagedSerializer,
     storage::mappers::SingleValue,
     types::{
-        Address, BigUint, CodeMetadata, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAddress,
-        ManagedBuffer, ManagedByteArray, ManagedOption, ManagedVec, MultiValueEncoded,
-        ReturnsNewManagedAddress, ReturnsResult, TestAddress, TestSCAddress, TestTokenIdentifier,
+        Address, BigUint, CodeMetadata, EgldOrEsdtTokenIdentifier, EsdtLocalRole, EsdtTokenPayment,
+        ManagedAddress, ManagedAsyncCallError, ManagedAsyncCallResult, ManagedBuffer,
+        ManagedByteArray, ManagedOption, ManagedVec, MultiValueEncoded, ReturnsNewManagedAddress,
+        ReturnsRawResult, ReturnsResult, TestAddress, TestSCAddress, TestTokenIdentifier,
         TokenIdentifier,
     },
 };
@@ -33,11 +35,16 @@ use multiversx_sc_scenario::{
     scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
     scenario_model::*,
     ContractInfo, DebugApi, ExpectError, ExpectValue, ScenarioTxRun, ScenarioWorld,
+    WhiteboxContract,
 };
 
 use eth_address::*;
 use token_module::ProxyTrait as _;
-use transaction::{CallData, EthTransaction};
+use transaction::{
+    multi_call_data::{CallStep, MultiCallData},
+    refund_reason::RefundReason,
+    CallData, EthTransaction, Transaction, TX_BATCH_VERSION,
+};
 
 const UNIVERSAL_TOKEN_IDENTIFIER: TestTokenIdentifier = TestTokenIdentifier::new("UNIV-abc123");
 const BRIDGE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("BRIDGE-123456");
@@ -57,12 +64,16 @@ const BRIDGED_TOKENS_WRAPPER_CODE_PATH: MxscPath =
     MxscPath::new("../bridged-tokens-wrapper/output/bridged-tokens-wrapper.mxsc.json");
 const PRICE_AGGREGATOR_CODE_PATH: MxscPath =
     MxscPath::new("../price-aggregator/price-aggregator.mxsc.json");
+const EGLD_WRAPPER_CODE_PATH: MxscPath =
+    MxscPath::new("tests/test-contract/multiversx-wegld-swap-sc.mxsc.json");
 
 const MULTI_TRANSFER_ADDRESS: TestSCAddress = TestSCAddress::new("multi-transfer");
 const BRIDGE_PROXY_ADDRESS: TestSCAddress = TestSCAddress::new("bridge-proxy");
 const ESDT_SAFE_ADDRESS: TestSCAddress = TestSCAddress::new("esdt-safe");
 const BRIDGED_TOKENS_WRAPPER_ADDRESS: TestSCAddress = TestSCAddress::new("bridged-tokens-wrapper");
 const PRICE_AGGREGATOR_ADDRESS: TestSCAddress = TestSCAddress::new("price-aggregator");
+const NON_PAYABLE_SC_ADDRESS: TestSCAddress = TestSCAddress::new("non-payable-sc");
+const EGLD_WRAPPER_ADDRESS: TestSCAddress = TestSCAddress::new("egld-wrapper");
 
 const ORACLE_ADDRESS: TestAddress = TestAddress::new("oracle");
 const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
@@ -90,6 +101,11 @@ fn world() -> ScenarioWorld {
         bridged_tokens_wrapper::ContractBuilder,
     );
 
+    blockchain.register_contract(
+        EGLD_WRAPPER_CODE_PATH,
+        multiversx_wegld_swap_sc::ContractBuilder,
+    );
+
     blockchain
 }
 
@@ -189,6 +205,25 @@ impl MultiTransferTestState {
         self
     }
 
+    fn egld_wrapper_deploy(&mut self) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(egld_wrapper_proxy::EgldEsdtSwapProxy)
+            .init(WRAPPED_TOKEN_ID)
+            .code(EGLD_WRAPPER_CODE_PATH)
+            .new_address(EGLD_WRAPPER_ADDRESS)
+            .run();
+
+        self.world.set_esdt_local_roles(
+            EGLD_WRAPPER_ADDRESS,
+            b"WRAPPED-123456",
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        self
+    }
+
     fn config_multi_transfer(&mut self) {
         self.world
             .tx()
@@ -512,6 +547,28 @@ impl MultiTransferTestState {
         );
     }
 
+    /// The test VM can not freeze accounts through the system SC, so the whole EsdtSafe account
+    /// is overwritten, storage included.
+    fn set_esdt_safe_token_frozen(&mut self, token_id: &str, frozen: bool) {
+        let roles = vec![
+            "ESDTRoleLocalMint".to_string(),
+            "ESDTRoleLocalBurn".to_string(),
+        ];
+        let mut esdt_safe_account = Account::new()
+            .esdt_roles(token_id, roles)
+            .code("mxsc:../esdt-safe/output/esdt-safe.mxsc.json")
+            .owner("address:owner");
+        esdt_safe_account
+            .esdt
+            .get_mut(&BytesKey::from(token_id))
+            .unwrap()
+            .get_mut_esdt_object()
+            .frozen = Some(U64Value::from(frozen as u64));
+
+        self.world
+            .set_state_step(SetStateStep::new().put_account("sc:esdt-safe", esdt_safe_account));
+    }
+
     fn deploy_contracts(&mut self) {
         self.multi_transfer_deploy();
         self.bridge_proxy_deploy();
@@ -722,7 +779,7 @@ fn batch_transfer_both_failed_test() {
         from: EthAddress {
             raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
         },
-        to: ManagedAddress::from(BRIDGE_PROXY_ADDRESS.eval_to_array()),
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
         token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
         amount: token_amount.clone(),
         tx_nonce: 1u64,
@@ -733,7 +790,7 @@ fn batch_transfer_both_failed_test() {
         from: EthAddress {
             raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
         },
-        to: ManagedAddress::from(BRIDGE_PROXY_ADDRESS.eval_to_array()),
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
         token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
         amount: token_amount.clone(),
         tx_nonce: 2u64,
@@ -1060,3 +1117,2055 @@ fn add_refund_batch_test() {
         BigUint::zero(),
     );
 }
+
+#[test]
+fn circuit_breaker_trip_and_reset_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .set_outflow_threshold(BRIDGE_TOKEN_ID, 600u64)
+        .run();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx.clone());
+    transfers.push(EthTransaction {
+        tx_nonce: 2u64,
+        ..eth_tx.clone()
+    });
+
+    // the transfer going over the threshold is still executed
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(1_000u64));
+
+    let trip_reason = state
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .circuit_breaker_trip_reason()
+        .returns(ReturnsResult)
+        .run();
+    assert!(trip_reason == circuit_breaker_module::CircuitBreakerReason::OutflowThresholdExceeded);
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(EthTransaction {
+        tx_nonce: 3u64,
+        ..eth_tx.clone()
+    });
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(2u32, transfers.clone())
+        .returns(ExpectError(ERROR, "EsdtSafe circuit breaker tripped"))
+        .run();
+
+    // a regular unpause does not clear the breaker
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .unpause_endpoint()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(2u32, transfers.clone())
+        .returns(ExpectError(ERROR, "EsdtSafe circuit breaker tripped"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .reset_circuit_breaker()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .set_outflow_threshold(BRIDGE_TOKEN_ID, 0u64)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(2u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(1_500u64));
+}
+
+#[test]
+fn batch_halted_after_circuit_breaker_trip_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // only 600 were ever burned, so minting more trips the breaker
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .add_token_to_whitelist(
+            TokenIdentifier::from_esdt_bytes("BRIDGE-123456"),
+            "BRIDGE",
+            true,
+            true,
+            BigUint::zero(),
+            BigUint::zero(),
+            BigUint::from(600u64),
+            OptionalValue::Some(BigUint::from(ESDT_SAFE_ETH_TX_GAS_LIMIT)),
+        )
+        .run();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx.clone());
+    transfers.push(EthTransaction {
+        tx_nonce: 2u64,
+        ..eth_tx.clone()
+    });
+    // would not break the invariant, but the breaker already tripped
+    transfers.push(EthTransaction {
+        amount: BigUint::from(100u64),
+        tx_nonce: 3u64,
+        ..eth_tx
+    });
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+
+    // the trip is kept, as the batch is not reverted
+    let trip_reason = state
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .circuit_breaker_trip_reason()
+        .returns(ReturnsResult)
+        .run();
+    assert!(trip_reason == circuit_breaker_module::CircuitBreakerReason::MintBurnInvariantViolated);
+
+    // the rest of the batch is halted instead of refunded
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+    assert!(refund_batch.into_option().is_none());
+
+    let halted_transfers = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_halted_transfers(1u64)
+        .returns(ReturnsResult)
+        .run();
+    let halted_nonces: Vec<u64> = halted_transfers
+        .into_iter()
+        .map(|eth_tx| eth_tx.tx_nonce)
+        .collect();
+    assert_eq!(halted_nonces, vec![2u64, 3u64]);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .resume_halted_transfers(1u64)
+        .returns(ExpectError(ERROR, "EsdtSafe circuit breaker tripped"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .init_supply_mint_burn(BRIDGE_TOKEN_ID, 500u64, 1_100u64)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .reset_circuit_breaker()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .resume_halted_transfers(1u64)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(1_100u64));
+
+    let halted_transfers = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_halted_transfers(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(halted_transfers.into_iter().next().is_none());
+}
+
+#[test]
+fn batch_transfer_paused_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .pause_endpoint()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers.clone())
+        .returns(ExpectError(ERROR, "Contract is paused"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .unpause_endpoint()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::from(500u64));
+}
+
+#[test]
+fn batch_transfer_paused_token_refund_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .pause_token(BRIDGE_TOKEN_ID)
+        .run();
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER2_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(WRAPPED_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER2_ADDRESS)
+        .esdt_balance(WRAPPED_TOKEN_ID, token_amount.clone());
+
+    // the paused token was never taken from the safe
+    state.check_balances_on_safe(
+        BRIDGE_TOKEN_ID,
+        BigUint::zero(),
+        BigUint::zero(),
+        BigUint::zero(),
+    );
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .move_refund_batch_to_safe()
+        .run();
+
+    let opt_refund_batch = state
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = opt_refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 1);
+
+    let (_, _, _, _, token_id, amount) = refund_txs.into_iter().next().unwrap().into_tuple();
+    assert_eq!(token_id, TokenIdentifier::from(BRIDGE_TOKEN_ID));
+    assert_eq!(amount, token_amount);
+
+    state.check_balances_on_safe(
+        BRIDGE_TOKEN_ID,
+        BigUint::zero(),
+        BigUint::zero(),
+        BigUint::zero(),
+    );
+}
+
+#[test]
+fn batch_transfer_token_paused_in_wrapper_refund_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGED_TOKENS_WRAPPER_ADDRESS)
+        .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+        .pause_token(WRAPPED_TOKEN_ID)
+        .run();
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER2_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(WRAPPED_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    // the wrapper would reject the paused token and revert the whole batch
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount.clone());
+
+    let versioned_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status_versioned()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, _, refund_txs) = versioned_batch.into_option().unwrap().into_tuple();
+    let refund_txs = refund_txs.to_vec();
+    assert_eq!(refund_txs.len(), 1);
+
+    let refund_tx = refund_txs.get(0);
+    assert_eq!(refund_tx.nonce, 2u64);
+    assert_eq!(
+        refund_tx.token_identifier,
+        TokenIdentifier::from(WRAPPED_TOKEN_ID)
+    );
+    assert!(refund_tx.refund_reason == RefundReason::TokenPaused);
+}
+
+#[test]
+fn batch_transfer_invalid_token_refund_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // more than the EsdtSafe holds, so getTokens fails
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(TOKEN_ID),
+        amount: BigUint::from(MAX_AMOUNT + 1),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER2_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER2_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount.clone());
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 1);
+}
+
+#[test]
+fn legacy_transaction_decode_test() {
+    // layout of the transactions stored before refund reasons and call data were added
+    let legacy_tx = (
+        1u64,
+        2u64,
+        ManagedBuffer::<StaticApi>::from(USER_ETHEREUM_ADDRESS),
+        ManagedBuffer::<StaticApi>::from("receiver"),
+        TokenIdentifier::<StaticApi>::from(BRIDGE_TOKEN_ID),
+        BigUint::<StaticApi>::from(500u64),
+        true,
+    );
+    let encoded = ManagedSerializer::new().top_encode_to_managed_buffer(&legacy_tx);
+    let tx: Transaction<StaticApi> =
+        ManagedSerializer::new().top_decode_from_managed_buffer(&encoded);
+
+    assert_eq!(tx.nonce, 2u64);
+    assert_eq!(tx.amount, BigUint::from(500u64));
+    assert!(tx.is_refund_tx);
+    assert!(tx.refund_reason == RefundReason::None);
+    assert_eq!(tx.original_batch_id, 0u64);
+    assert!(tx.call_data.is_none());
+
+    let tx = Transaction {
+        refund_reason: RefundReason::OverMaxAmount,
+        original_batch_id: 3u64,
+        call_data: ManagedOption::some(ManagedBuffer::from("call data")),
+        ..tx
+    };
+    let encoded = ManagedSerializer::new().top_encode_to_managed_buffer(&tx);
+    let tx: Transaction<StaticApi> =
+        ManagedSerializer::new().top_decode_from_managed_buffer(&encoded);
+
+    assert!(tx.refund_reason == RefundReason::OverMaxAmount);
+    assert_eq!(tx.original_batch_id, 3u64);
+    assert!(tx.call_data.into_option().unwrap() == ManagedBuffer::from("call data"));
+}
+
+#[test]
+fn batch_transfer_refund_versioned_batch_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // more than the EsdtSafe holds, so getTokens fails
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(TOKEN_ID),
+        amount: BigUint::from(MAX_AMOUNT + 1),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    let legacy_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (legacy_batch_id, legacy_txs) = legacy_batch.into_option().unwrap().into_tuple();
+    assert_eq!(legacy_txs.len(), 1);
+
+    // the legacy view keeps its 6 fields per transaction
+    let raw_legacy_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsRawResult)
+        .run();
+    assert_eq!(raw_legacy_batch.len(), 1 + 6);
+
+    let versioned_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status_versioned()
+        .returns(ReturnsResult)
+        .run();
+
+    let (version, batch_id, refund_txs) = versioned_batch.into_option().unwrap().into_tuple();
+    assert_eq!(version, TX_BATCH_VERSION);
+    assert_eq!(batch_id, legacy_batch_id);
+
+    let refund_txs = refund_txs.to_vec();
+    assert_eq!(refund_txs.len(), 1);
+
+    let refund_tx = refund_txs.get(0);
+    assert_eq!(refund_tx.nonce, 1u64);
+    assert!(refund_tx.is_refund_tx);
+    assert!(refund_tx.refund_reason == RefundReason::InvalidToken);
+    assert_eq!(refund_tx.original_batch_id, 1u64);
+
+    let versioned_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_batch_versioned(batch_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(versioned_batch.is_some());
+}
+
+#[test]
+fn batch_transfer_invalid_call_data_refund_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    let call_data: CallData<StaticApi> = CallData {
+        endpoint: ManagedBuffer::from("add"),
+        gas_limit: 1_000u64,
+        args: ManagedOption::none(),
+    };
+    let low_gas_call_data: ManagedBuffer<StaticApi> =
+        ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(ManagedBuffer::from(b"invalid")),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::some(low_gas_call_data),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    // neither transaction reaches the BridgeProxy
+    state
+        .world
+        .check_account(BRIDGE_PROXY_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::zero());
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 2);
+}
+
+#[test]
+fn batch_transfer_call_to_bridge_proxy_refund_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    let bridge_proxy_address = ManagedAddress::from(BRIDGE_PROXY_ADDRESS.eval_to_array());
+    let mut args = ManagedVec::new();
+    args.push(ManagedBuffer::from(&[1u8][..]));
+    let call_data: CallData<StaticApi> = CallData {
+        endpoint: ManagedBuffer::from("executeCalls"),
+        gas_limit: GAS_LIMIT,
+        args: ManagedOption::some(args),
+    };
+    let call_data: ManagedBuffer<StaticApi> =
+        ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let mut calls = ManagedVec::new();
+    calls.push(CallStep {
+        to: bridge_proxy_address.clone(),
+        call_data: CallData {
+            endpoint: ManagedBuffer::from("executeCalls"),
+            gas_limit: GAS_LIMIT,
+            args: ManagedOption::none(),
+        },
+    });
+    let multi_call_data: MultiCallData<StaticApi> = MultiCallData::new(calls);
+    let multi_call_data: ManagedBuffer<StaticApi> =
+        ManagedSerializer::new().top_encode_to_managed_buffer(&multi_call_data);
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: bridge_proxy_address,
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::some(multi_call_data),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    // the BridgeProxy never receives calls targeting itself
+    state
+        .world
+        .check_account(BRIDGE_PROXY_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::zero());
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 2);
+}
+
+#[test]
+fn batch_transfer_not_whitelisted_token_refund_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // not whitelisted in the EsdtSafe
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(UNIVERSAL_TOKEN_IDENTIFIER),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER2_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(USER2_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount.clone());
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 1);
+}
+
+#[test]
+fn batch_transfer_quarantine_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+    let small_amount = BigUint::from(50u64);
+    let quarantine_delay = 10u64;
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_review_threshold(BRIDGE_TOKEN_ID, BigUint::from(100u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_quarantine_delay(quarantine_delay)
+        .run();
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER2_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: small_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx3 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER2_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 3u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+    transfers.push(eth_tx3);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    // only the transfer below the review threshold is delivered
+    state
+        .world
+        .check_account(USER2_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, small_amount.clone());
+
+    for tx_nonce in [1u64, 3u64] {
+        state
+            .world
+            .query()
+            .to(MULTI_TRANSFER_ADDRESS)
+            .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+            .is_transfer_quarantined(tx_nonce)
+            .returns(ExpectValue(true))
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .release_quarantined_transfer(1u64)
+        .returns(ExpectError(ERROR, "Quarantine period not over"))
+        .run();
+
+    // refunds do not wait for the quarantine period
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .refund_quarantined_transfer(3u64)
+        .run();
+
+    state.world.current_block().block_nonce(quarantine_delay);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .release_quarantined_transfer(1u64)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount.clone());
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .release_quarantined_transfer(1u64)
+        .returns(ExpectError(ERROR, "Transfer is not quarantined"))
+        .run();
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 1);
+
+    let (_, _, _, _, token_id, amount) = refund_txs.into_iter().next().unwrap().into_tuple();
+    assert_eq!(token_id, TokenIdentifier::from(BRIDGE_TOKEN_ID));
+    assert_eq!(amount, token_amount);
+}
+
+#[test]
+fn batch_transfer_cross_shard_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    // the shard is given by the last address byte, this one is not in the contracts' shard
+    let cross_shard_user = Address::from(*b"cross-shard-user_______________0");
+    state.world.account(&cross_shard_user).nonce(1);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(&cross_shard_user),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(&cross_shard_user)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount);
+
+    // the callback clears the tracking of successful deliveries
+    let pending_transfers = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .pending_cross_shard_transfers()
+        .returns(ReturnsResult)
+        .run();
+    assert!(pending_transfers.is_empty());
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+    assert!(refund_batch.into_option().is_none());
+}
+
+#[test]
+fn batch_transfer_cross_shard_limit_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(400u64);
+
+    let cross_shard_user = Address::from(*b"cross-shard-user_______________0");
+    let cross_shard_user2 = Address::from(*b"cross-shard-user2______________0");
+    state.world.account(&cross_shard_user).nonce(1);
+    state.world.account(&cross_shard_user2).nonce(1);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_max_cross_shard_transfers_per_batch(0usize)
+        .returns(ExpectError(
+            ERROR,
+            "Max cross-shard transfers per batch must be more than 0",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_max_cross_shard_transfers_per_batch(1usize)
+        .run();
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    for (tx_nonce, user) in [(1u64, &cross_shard_user), (2u64, &cross_shard_user2)] {
+        transfers.push(EthTransaction {
+            from: EthAddress::zero(),
+            to: ManagedAddress::from(user),
+            token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+            amount: token_amount.clone(),
+            tx_nonce,
+            call_data: ManagedOption::none(),
+        });
+    }
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .check_account(&cross_shard_user)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount);
+
+    // over the limit, escrowed for the recipient to claim
+    state
+        .world
+        .check_account(&cross_shard_user2)
+        .esdt_balance(BRIDGE_TOKEN_ID, BigUint::zero());
+
+    let claimable_deposits = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_claimable_deposits(cross_shard_user2.clone())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(claimable_deposits.into_iter().count(), 1);
+}
+
+#[test]
+fn batch_transfer_cross_shard_failed_refund_test() {
+    let mut state = MultiTransferTestState::new();
+
+    let cross_shard_user = Address::from(*b"cross-shard-user_______________0");
+    state.world.account(&cross_shard_user).nonce(1);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // plain transfers can not fail in the test VM, so the failed result is fed to the callback
+    let multi_transfer_whitebox =
+        WhiteboxContract::new("sc:multi-transfer", multi_transfer_esdt::contract_obj);
+    state.world.whitebox_call(
+        &multi_transfer_whitebox,
+        ScCallStep::new().from("address:owner"),
+        |sc| {
+            let eth_tx = EthTransaction {
+                from: EthAddress::zero(),
+                to: ManagedAddress::from(&cross_shard_user),
+                token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+                amount: BigUint::from(500u64),
+                tx_nonce: 1u64,
+                call_data: ManagedOption::none(),
+            };
+            let _ = sc.pending_cross_shard_transfers().insert(1u64, eth_tx);
+
+            sc.cross_shard_transfer_callback(
+                ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                    err_code: 10,
+                    err_msg: ManagedBuffer::from("frozen"),
+                }),
+                1u64,
+                1u64,
+                EsdtTokenPayment::new(TokenIdentifier::from(BRIDGE_TOKEN_ID), 0, 500u64.into()),
+            );
+        },
+    );
+
+    let pending_transfers = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .pending_cross_shard_transfers()
+        .returns(ReturnsResult)
+        .run();
+    assert!(pending_transfers.is_empty());
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    let refund_txs: Vec<_> = refund_txs
+        .into_iter()
+        .map(|refund_tx| refund_tx.into_tuple())
+        .collect();
+    assert_eq!(refund_txs.len(), 1);
+    let (_, nonce, _, to, token_id, amount) = &refund_txs[0];
+    assert_eq!(*nonce, 1u64);
+    assert_eq!(*to, ManagedBuffer::from(cross_shard_user.as_bytes()));
+    assert_eq!(*token_id, TokenIdentifier::from(BRIDGE_TOKEN_ID));
+    assert_eq!(*amount, BigUint::from(500u64));
+}
+
+#[test]
+fn batch_transfer_non_payable_sc_escrow_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+    let claim_expiry_duration = 10u64;
+
+    state
+        .world
+        .account(NON_PAYABLE_SC_ADDRESS)
+        .code(BRIDGE_PROXY_CODE_PATH)
+        .owner(OWNER_ADDRESS);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // without the proxy, SC receivers get the tokens directly
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_bridge_proxy_contract_address(OptionalValue::None)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_claim_expiry_duration(claim_expiry_duration)
+        .run();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(NON_PAYABLE_SC_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    let claimable_deposits = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_claimable_deposits(NON_PAYABLE_SC_ADDRESS.to_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(claimable_deposits.into_iter().count(), 1);
+
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .claim_bridged_tokens()
+        .returns(ExpectError(ERROR, "Nothing to claim"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .refund_expired_deposit(1u64)
+        .returns(ExpectError(ERROR, "Deposit has not expired yet"))
+        .run();
+
+    state.world.current_block().block_nonce(claim_expiry_duration);
+
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .refund_expired_deposit(1u64)
+        .run();
+
+    let claimable_deposits = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_claimable_deposits(NON_PAYABLE_SC_ADDRESS.to_address())
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(claimable_deposits.into_iter().count(), 0);
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 1);
+
+    let (_, _, _, _, token_id, amount) = refund_txs.into_iter().next().unwrap().into_tuple();
+    assert_eq!(token_id, TokenIdentifier::from(BRIDGE_TOKEN_ID));
+    assert_eq!(amount, token_amount);
+}
+
+#[test]
+fn refund_below_fee_unrefundable_dust_test() {
+    let mut state = MultiTransferTestState::new();
+    let dust_amount = BigUint::from(1_000u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    // invalid destination, so the tokens are taken from the safe and refunded
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::zero(),
+        token_id: TokenIdentifier::from(TOKEN_ID),
+        amount: dust_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .move_refund_batch_to_safe()
+        .run();
+
+    // the refund does not cover the fee, so no refund transaction is created
+    let opt_refund_batch = state
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+    assert!(opt_refund_batch.into_option().is_none());
+
+    state
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .unrefundable_dust(TOKEN_ID)
+        .returns(ExpectValue(dust_amount.clone()))
+        .run();
+
+    state.check_balances_on_safe(
+        TOKEN_ID,
+        BigUint::from(MAX_AMOUNT) - &dust_amount,
+        BigUint::zero(),
+        BigUint::zero(),
+    );
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .recredit_unrefundable_dust(TOKEN_ID, USER1_ADDRESS.to_address(), dust_amount.clone())
+        .run();
+
+    state
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .unrefundable_dust(TOKEN_ID)
+        .returns(ExpectValue(BigUint::zero()))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .claim_refund(TOKEN_ID)
+        .run();
+
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(TOKEN_ID, dust_amount.clone());
+
+    state.check_balances_on_safe(
+        TOKEN_ID,
+        BigUint::from(MAX_AMOUNT) - &dust_amount,
+        BigUint::zero(),
+        BigUint::zero(),
+    );
+}
+
+#[test]
+fn retry_unprocessed_refund_txs_empty_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .retry_unprocessed_refund_txs()
+        .returns(ExpectValue(0usize))
+        .run();
+
+    let unprocessed_refund_txs = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_unprocessed_refund_txs()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(unprocessed_refund_txs.into_iter().count(), 0);
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+    assert!(refund_batch.into_option().is_none());
+}
+
+#[test]
+fn retry_unprocessed_refund_txs_after_unfreeze_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    let eth_tx = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::zero(),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: BigUint::from(500u64),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    // the refund can not be sent to a frozen EsdtSafe, so it is parked
+    state.set_esdt_safe_token_frozen("str:BRIDGE-123456", true);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .move_refund_batch_to_safe()
+        .run();
+
+    let unprocessed_refund_txs = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_unprocessed_refund_txs()
+        .returns(ReturnsResult)
+        .run();
+    let unprocessed_nonces: Vec<u64> = unprocessed_refund_txs
+        .into_iter()
+        .map(|refund_tx| refund_tx.nonce)
+        .collect();
+    assert_eq!(unprocessed_nonces, vec![1u64]);
+
+    // parked before the nonces were tracked
+    let multi_transfer_whitebox =
+        WhiteboxContract::new("sc:multi-transfer", multi_transfer_esdt::contract_obj);
+    state.world.whitebox_call(
+        &multi_transfer_whitebox,
+        ScCallStep::new().from("address:owner"),
+        |sc| {
+            sc.unprocessed_refund_tx_nonces().swap_remove(&1u64);
+        },
+    );
+
+    let mut tx_nonces: MultiValueEncoded<StaticApi, u64> = MultiValueEncoded::new();
+    tx_nonces.push(1u64);
+    tx_nonces.push(2u64);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .register_unprocessed_refund_txs(tx_nonces.clone())
+        .returns(ExpectValue(1usize))
+        .run();
+
+    // already registered
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .register_unprocessed_refund_txs(tx_nonces)
+        .returns(ExpectValue(0usize))
+        .run();
+
+    // still frozen, nothing to re-queue
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .retry_unprocessed_refund_txs()
+        .returns(ExpectValue(0usize))
+        .run();
+
+    state.set_esdt_safe_token_frozen("str:BRIDGE-123456", false);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .retry_unprocessed_refund_txs()
+        .returns(ExpectValue(1usize))
+        .run();
+
+    let unprocessed_refund_txs = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_unprocessed_refund_txs()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(unprocessed_refund_txs.into_iter().count(), 0);
+
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    let refund_nonces: Vec<u64> = refund_txs
+        .into_iter()
+        .map(|refund_tx| refund_tx.into_tuple().1)
+        .collect();
+    assert_eq!(refund_nonces, vec![1u64]);
+}
+
+#[test]
+fn set_auto_execute_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .auto_execute_enabled()
+        .returns(ExpectValue(false))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_auto_execute(true)
+        .returns(ExpectError(ERROR, "Endpoint can only be called by owner"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_auto_execute(true)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .auto_execute_enabled()
+        .returns(ExpectValue(true))
+        .run();
+}
+
+#[test]
+fn batch_transfer_auto_execute_rejected_call_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_auto_execute(true)
+        .run();
+
+    // no target is allowed, so the BridgeProxy rejects the call when it is auto-executed
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .set_allowlist_enabled(true)
+        .run();
+
+    let call_data: CallData<StaticApi> = CallData {
+        endpoint: ManagedBuffer::from("add"),
+        gas_limit: GAS_LIMIT,
+        args: ManagedOption::none(),
+    };
+    let call_data: ManagedBuffer<StaticApi> =
+        ManagedSerializer::new().top_encode_to_managed_buffer(&call_data);
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(BRIDGED_TOKENS_WRAPPER_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::some(call_data),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .gas(300_000_000)
+        .run();
+
+    // the rest of the batch is not reverted by the rejection
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount.clone());
+
+    state
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .get_transaction_status(1u32)
+        .returns(ExpectValue(MultiValue2::from((
+            bridge_proxy_contract_proxy::ExecutionStatus::RefundPending,
+            0u64,
+        ))))
+        .run();
+
+    // the payment waits in the BridgeProxy to be settled
+    let unsettled_payments = state
+        .world
+        .query()
+        .to(BRIDGE_PROXY_ADDRESS)
+        .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+        .unsettled_payments(1u32)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(unsettled_payments.len(), 1);
+    assert_eq!(unsettled_payments.get(0).amount, token_amount);
+
+    state
+        .world
+        .check_account(BRIDGE_PROXY_ADDRESS)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount);
+}
+
+#[test]
+fn set_native_egld_delivery_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state
+        .world
+        .account(EGLD_WRAPPER_ADDRESS)
+        .code(BRIDGE_PROXY_CODE_PATH)
+        .owner(OWNER_ADDRESS);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_native_egld_delivery(TOKEN_ID, true)
+        .returns(ExpectError(ERROR, "EGLD wrapper contract address not set"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_egld_wrapper_contract_address(OptionalValue::Some(USER1_ADDRESS.to_address()))
+        .returns(ExpectError(ERROR, "Invalid EGLD wrapper contract address"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_egld_wrapper_contract_address(OptionalValue::Some(EGLD_WRAPPER_ADDRESS.to_address()))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_native_egld_delivery(TOKEN_ID, true)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .native_egld_delivery(TOKEN_ID)
+        .returns(ExpectValue(true))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .native_egld_delivery(BRIDGE_TOKEN_ID)
+        .returns(ExpectValue(false))
+        .run();
+}
+
+#[test]
+fn batch_transfer_native_egld_delivery_test() {
+    let mut state = MultiTransferTestState::new();
+    let token_amount = BigUint::from(500u64);
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+    state.egld_wrapper_deploy();
+
+    // the EGLD wrapper needs EGLD locked before it can unwrap anything
+    state
+        .world
+        .account(USER2_ADDRESS)
+        .nonce(1)
+        .balance(1_000u64);
+    state
+        .world
+        .tx()
+        .from(USER2_ADDRESS)
+        .to(EGLD_WRAPPER_ADDRESS)
+        .typed(egld_wrapper_proxy::EgldEsdtSwapProxy)
+        .wrap_egld()
+        .egld(1_000u64)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_egld_wrapper_contract_address(OptionalValue::Some(EGLD_WRAPPER_ADDRESS.to_address()))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .set_native_egld_delivery(WRAPPED_TOKEN_ID, true)
+        .run();
+
+    let eth_tx1 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(WRAPPED_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 1u64,
+        call_data: ManagedOption::none(),
+    };
+    let eth_tx2 = EthTransaction {
+        from: EthAddress::zero(),
+        to: ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        token_id: TokenIdentifier::from(BRIDGE_TOKEN_ID),
+        amount: token_amount.clone(),
+        tx_nonce: 2u64,
+        call_data: ManagedOption::none(),
+    };
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTransaction<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx1);
+    transfers.push(eth_tx2);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .batch_transfer_esdt_token(1u32, transfers)
+        .run();
+
+    // the wrapped EGLD arrives unwrapped, the other tokens are untouched
+    state
+        .world
+        .check_account(USER1_ADDRESS)
+        .balance(500u64)
+        .esdt_balance(WRAPPED_TOKEN_ID, 0u64)
+        .esdt_balance(BRIDGE_TOKEN_ID, token_amount);
+
+    state
+        .world
+        .query()
+        .to(EGLD_WRAPPER_ADDRESS)
+        .typed(egld_wrapper_proxy::EgldEsdtSwapProxy)
+        .get_locked_egld_balance()
+        .returns(ExpectValue(BigUint::from(500u64)))
+        .run();
+}
+
+#[test]
+fn create_transaction_native_egld_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.deploy_contracts();
+    state.config_multi_transfer();
+    state.config_bridged_tokens_wrapper();
+    state.egld_wrapper_deploy();
+
+    state
+        .world
+        .account(USER2_ADDRESS)
+        .nonce(1)
+        .balance(1_000u64);
+
+    state
+        .world
+        .tx()
+        .from(USER2_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .create_transaction(
+            EthAddress::zero(),
+            OptionalValue::<esdt_safe_proxy::RefundInfo<StaticApi>>::None,
+        )
+        .egld(1_000u64)
+        .returns(ExpectError(ERROR, "Native EGLD is not accepted"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .set_egld_wrapper_contract_address(OptionalValue::Some(EGLD_WRAPPER_ADDRESS.to_address()))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER2_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(esdt_safe_proxy::EsdtSafeProxy)
+        .create_transaction(
+            EthAddress::zero(),
+            OptionalValue::<esdt_safe_proxy::RefundInfo<StaticApi>>::None,
+        )
+        .egld(1_000u64)
+        .run();
+
+    // the EGLD is locked in the wrapper and bridged as wrapped EGLD
+    state.world.check_account(USER2_ADDRESS).balance(0u64);
+
+    state
+        .world
+        .query()
+        .to(EGLD_WRAPPER_ADDRESS)
+        .typed(egld_wrapper_proxy::EgldEsdtSwapProxy)
+        .get_locked_egld_balance()
+        .returns(ExpectValue(BigUint::from(1_000u64)))
+        .run();
+
+    state.check_balances_on_safe(
+        WRAPPED_TOKEN_ID,
+        BigUint::zero(),
+        BigUint::from(600000u64),
+        BigUint::from(1_000u64),
+    );
+}
@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           19
+// Endpoints:                           61
 // Async Callback (empty):               1
-// Total number of exported functions:  22
+// Promise callbacks:                    1
+// Total number of exported functions:  65
 
 #![no_std]
 
@@ -21,24 +22,67 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         batchTransferEsdtToken => batch_transfer_esdt_token
+        resumeHaltedTransfers => resume_halted_transfers
+        getHaltedTransfers => get_halted_transfers
         moveRefundBatchToSafe => move_refund_batch_to_safe
         setWrappingContractAddress => set_wrapping_contract_address
+        setEgldWrapperContractAddress => set_egld_wrapper_contract_address
+        setNativeEgldDelivery => set_native_egld_delivery
         setBridgeProxyContractAddress => set_bridge_proxy_contract_address
         addUnprocessedRefundTxToBatch => add_unprocessed_refund_tx_to_batch
+        retryUnprocessedRefundTxs => retry_unprocessed_refund_txs
+        registerUnprocessedRefundTxs => register_unprocessed_refund_txs
+        getUnprocessedRefundTxs => get_unprocessed_refund_txs
         setEsdtSafeContractAddress => set_esdt_safe_contract_address
+        setReviewThreshold => set_review_threshold
+        setQuarantineDelay => set_quarantine_delay
+        setClaimExpiryDuration => set_claim_expiry_duration
+        setCrossShardTransferGasLimits => set_cross_shard_transfer_gas_limits
+        setMaxCrossShardTransfersPerBatch => set_max_cross_shard_transfers_per_batch
+        setAutoExecute => set_auto_execute
+        releaseQuarantinedTransfer => release_quarantined_transfer
+        refundQuarantinedTransfer => refund_quarantined_transfer
+        isTransferQuarantined => is_transfer_quarantined
+        claimBridgedTokens => claim_bridged_tokens
+        refundExpiredDeposit => refund_expired_deposit
+        getClaimableDeposits => get_claimable_deposits
         getWrappingContractAddress => wrapping_contract_address
+        getEgldWrapperContractAddress => egld_wrapper_contract_address
+        isNativeEgldDeliveryEnabled => native_egld_delivery
         getBridgeProxyContractAddress => bridge_proxy_contract_address
         getEsdtSafeContractAddress => esdt_safe_contract_address
+        getUnprocessedRefundTx => unprocessed_refund_txs
+        getHaltedBatchIds => halted_batch_ids
+        getUnprocessedRefundTxNonces => unprocessed_refund_tx_nonces
+        getReviewThreshold => review_threshold
+        getQuarantineDelay => quarantine_delay
+        getQuarantinedTransfers => quarantined_transfers
+        getPendingCrossShardTransfers => pending_cross_shard_transfers
+        getCrossShardTransferGasLimit => cross_shard_transfer_gas_limit
+        getCrossShardTransferCallbackGasLimit => cross_shard_transfer_callback_gas_limit
+        getMaxCrossShardTransfersPerBatch => max_cross_shard_transfers_per_batch
+        getClaimExpiryDuration => claim_expiry_duration
+        isAutoExecuteEnabled => auto_execute_enabled
         setMaxTxBatchSize => set_max_tx_batch_size
         setMaxTxBatchBlockDuration => set_max_tx_batch_block_duration
         getCurrentTxBatch => get_current_tx_batch
         getFirstBatchAnyStatus => get_first_batch_any_status
         getBatch => get_batch
+        getCurrentTxBatchVersioned => get_current_tx_batch_versioned
+        getFirstBatchAnyStatusVersioned => get_first_batch_any_status_versioned
+        getBatchVersioned => get_batch_versioned
         getBatchStatus => get_batch_status
         getFirstBatchId => first_batch_id
         getLastBatchId => last_batch_id
         setMaxBridgedAmount => set_max_bridged_amount
         getMaxBridgedAmount => max_bridged_amount
+        pause => pause_endpoint
+        unpause => unpause_endpoint
+        isPaused => paused_status
+        pauseToken => pause_token
+        unpauseToken => unpause_token
+        isTokenPaused => token_paused
+        cross_shard_transfer_callback => cross_shard_transfer_callback
     )
 }
 
@@ -17,6 +17,13 @@ pub enum Action<M: ManagedTypeApi> {
         eth_batch_id: u64,
         transfers: ManagedVec<M, EthTransaction<M>>,
     },
+    ResetEsdtSafeCircuitBreaker,
+    ReleaseQuarantinedTransfer {
+        tx_nonce: u64,
+    },
+    RefundQuarantinedTransfer {
+        tx_nonce: u64,
+    },
 }
 
 impl<M: ManagedTypeApi> Action<M> {
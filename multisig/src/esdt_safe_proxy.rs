@@ -146,25 +146,91 @@ where
             .original_result()
     }
 
+    /// Same as `addRefundBatch`, but for failed Ethereum -> MultiversX transactions 
+    /// whose tokens never left the EsdtSafe, e.g. because the token was paused. 
+    ///  
+    /// Since no tokens are moved, no fees are subtracted and the balances are left untouched. 
+    pub fn add_unfunded_refund_batch<
+        Arg0: ProxyArg<ManagedVec<Env::Api, transaction::Transaction<Env::Api>>>,
+    >(
+        self,
+        refund_transactions: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addUnfundedRefundBatch")
+            .argument(&refund_transactions)
+            .original_result()
+    }
+
     /// Create an MultiversX -> Ethereum transaction. Only fungible tokens are accepted. 
+    /// Native EGLD is also accepted if an EGLD wrapper contract is set, and bridged as wrapped EGLD. 
     ///  
     /// Every transfer will have a part of the tokens subtracted as fees. 
     /// The fee amount depends on the global eth_tx_gas_limit 
     /// and the current GWEI price, respective to the bridged token 
     ///  
     /// fee_amount = price_per_gas_unit * eth_tx_gas_limit 
+    ///  
+    /// The BridgeProxy passes the reason of its refunds, `ProxyExecutionFailed` is assumed otherwise. 
     pub fn create_transaction<
         Arg0: ProxyArg<eth_address::EthAddress<Env::Api>>,
         Arg1: ProxyArg<OptionalValue<RefundInfo<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<transaction::refund_reason::RefundReason>>,
     >(
         self,
         to: Arg0,
         opt_refund_info: Arg1,
+        opt_refund_reason: Arg2,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("createTransaction")
             .argument(&to)
             .argument(&opt_refund_info)
+            .argument(&opt_refund_reason)
+            .original_result()
+    }
+
+    /// Same as `createTransaction`, but the Ethereum side also calls `target` with `data` 
+    /// after the transfer, using at most `gas_limit` gas. 
+    ///  
+    /// The gas of the call is paid for as well: 
+    /// fee_amount = price_per_gas_unit * (eth_tx_gas_limit + gas_limit) 
+    pub fn create_transaction_with_call_data<
+        Arg0: ProxyArg<eth_address::EthAddress<Env::Api>>,
+        Arg1: ProxyArg<eth_address::EthAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        to: Arg0,
+        target: Arg1,
+        data: Arg2,
+        gas_limit: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createTransactionWithCallData")
+            .argument(&to)
+            .argument(&target)
+            .argument(&data)
+            .argument(&gas_limit)
+            .original_result()
+    }
+
+    /// Same as `createTransaction`, but for multiple payments at once. 
+    /// `destinations` holds either a single address, used for all the payments, 
+    /// or one address for each payment, in the same order. 
+    ///  
+    /// The fee is queried once per token. 
+    pub fn create_transactions<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, eth_address::EthAddress<Env::Api>>>,
+    >(
+        self,
+        destinations: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("createTransactions")
+            .argument(&destinations)
             .original_result()
     }
 
@@ -197,6 +263,37 @@ where
             .original_result()
     }
 
+    pub fn set_egld_wrapper_contract_address<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEgldWrapperContractAddress")
+            .argument(&opt_new_address)
+            .original_result()
+    }
+
+    /// Max gas a call executed on the Ethereum side may request. 
+    /// 0 disables `createTransactionWithCallData`. 
+    ///  
+    /// `getCurrentTxBatch` does not include the call data, so it must only be set above 0 
+    /// once the relayers read the batches through `getCurrentTxBatchVersioned`. 
+    pub fn set_max_outbound_call_gas_limit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_gas_limit: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxOutboundCallGasLimit")
+            .argument(&max_gas_limit)
+            .original_result()
+    }
+
     pub fn set_bridge_proxy_contract_address<
         Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
@@ -242,6 +339,44 @@ where
             .original_result()
     }
 
+    /// Sends the refunds that were too small to cover the fees to the given address. 
+    pub fn withdraw_unrefundable_dust<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        multisig_owner: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawUnrefundableDust")
+            .argument(&token_id)
+            .argument(&multisig_owner)
+            .original_result()
+    }
+
+    /// Makes part of the unrefundable dust claimable by the given address, through `claimRefund`. 
+    /// Usually the original sender, which can be found in the `unrefundableDust` events. 
+    pub fn recredit_unrefundable_dust<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        receiver: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recreditUnrefundableDust")
+            .argument(&token_id)
+            .argument(&receiver)
+            .argument(&amount)
+            .original_result()
+    }
+
     pub fn compute_total_amounts_from_index<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<u64>,
@@ -295,6 +430,32 @@ where
             .original_result()
     }
 
+    pub fn get_total_unrefundable_dust(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<TokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalUnrefundableDust")
+            .original_result()
+    }
+
+    /// Returns the fee for a transfer followed by a call using `call_gas_limit` gas 
+    pub fn calculate_required_fee_with_call_data<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        call_gas_limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("calculateRequiredFeeWithCallData")
+            .argument(&token_id)
+            .argument(&call_gas_limit)
+            .original_result()
+    }
+
     pub fn get_transaction_fees<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
@@ -308,6 +469,20 @@ where
             .original_result()
     }
 
+    /// Refunds that were too small to cover the fees 
+    pub fn unrefundable_dust<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnrefundableDust")
+            .argument(&token_id)
+            .original_result()
+    }
+
     pub fn bridged_tokens_wrapper_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -326,6 +501,24 @@ where
             .original_result()
     }
 
+    pub fn egld_wrapper_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEgldWrapperContractAddress")
+            .original_result()
+    }
+
+    pub fn max_outbound_call_gas_limit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxOutboundCallGasLimit")
+            .original_result()
+    }
+
     pub fn set_fee_estimator_contract_address<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -679,6 +872,8 @@ where
             .original_result()
     }
 
+    /// Legacy layout, without the refund info and the call data of the transactions, 
+    /// see `getCurrentTxBatchVersioned`. 
     pub fn get_current_tx_batch(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue2<u64, MultiValueEncoded<Env::Api, MultiValue6<u64, u64, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, TokenIdentifier<Env::Api>, BigUint<Env::Api>>>>>> {
@@ -710,6 +905,39 @@ where
             .original_result()
     }
 
+    /// Same as `getCurrentTxBatch`, but returns a layout version tag, the batch ID, 
+    /// then full `Transaction` structs, including the refund info and the call data. 
+    pub fn get_current_tx_batch_versioned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentTxBatchVersioned")
+            .original_result()
+    }
+
+    pub fn get_first_batch_any_status_versioned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFirstBatchAnyStatusVersioned")
+            .original_result()
+    }
+
+    pub fn get_batch_versioned<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBatchVersioned")
+            .argument(&batch_id)
+            .original_result()
+    }
+
     pub fn get_batch_status<
         Arg0: ProxyArg<u64>,
     >(
@@ -796,6 +1024,129 @@ where
             .raw_call("isPaused")
             .original_result()
     }
+
+    /// Max amount of the given token that may leave the contract in a single window. 
+    /// 0 disables outflow tracking for the token. 
+    pub fn set_outflow_threshold<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        threshold: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOutflowThreshold")
+            .argument(&token_id)
+            .argument(&threshold)
+            .original_result()
+    }
+
+    pub fn set_outflow_window_duration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        window_duration: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOutflowWindowDuration")
+            .argument(&window_duration)
+            .original_result()
+    }
+
+    pub fn reset_circuit_breaker(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resetCircuitBreaker")
+            .original_result()
+    }
+
+    pub fn outflow_threshold<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOutflowThreshold")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn outflow_window_duration(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOutflowWindowDuration")
+            .original_result()
+    }
+
+    pub fn outflow_in_window<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOutflowInWindow")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn circuit_breaker_trip_reason(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, circuit_breaker_module::CircuitBreakerReason> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCircuitBreakerTripReason")
+            .original_result()
+    }
+
+    pub fn pause_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn unpause_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn token_paused<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isTokenPaused")
+            .argument(&token_id)
+            .original_result()
+    }
 }
 
 #[type_abi]
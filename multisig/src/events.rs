@@ -22,9 +22,40 @@ pub trait EventsModule {
     #[event("addUnprocessedRefundTxToBatchEvent")]
     fn add_unprocessed_refund_tx_to_batch_event(&self, #[indexed] tx_id: u64);
 
+    #[event("retryUnprocessedRefundTxsEvent")]
+    fn retry_unprocessed_refund_txs_event(&self, #[indexed] nr_requeued_txs: usize);
+
+    #[event("registerUnprocessedRefundTxsEvent")]
+    fn register_unprocessed_refund_txs_event(&self, #[indexed] nr_registered_txs: usize);
+
+    #[event("resumeHaltedTransfersEvent")]
+    fn resume_halted_transfers_event(&self, #[indexed] batch_id: u64);
+
     #[event("pauseBridgeProxyEvent")]
     fn pause_bridge_proxy_event(&self);
 
     #[event("unpauseBridgeProxyEvent")]
     fn unpause_bridge_proxy_event(&self);
+
+    #[event("pauseMultiTransferEvent")]
+    fn pause_multi_transfer_event(&self);
+
+    #[event("unpauseMultiTransferEvent")]
+    fn unpause_multi_transfer_event(&self);
+
+    #[event("pauseTokenEvent")]
+    fn pause_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[event("unpauseTokenEvent")]
+    fn unpause_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    #[event("emergencyPauseEvent")]
+    fn emergency_pause_event(&self, #[indexed] guardian: &ManagedAddress);
+
+    #[event("bestEffortCallFailedEvent")]
+    fn best_effort_call_failed_event(
+        &self,
+        #[indexed] sc_address: &ManagedAddress,
+        #[indexed] err_code: u32,
+    );
 }
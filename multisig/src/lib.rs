@@ -11,14 +11,16 @@ mod user_role;
 mod util;
 
 pub mod bridge_proxy_contract_proxy;
+pub mod bridged_tokens_wrapper_proxy;
 pub mod esdt_safe_proxy;
 pub mod multi_transfer_esdt_proxy;
 pub mod multisig_proxy;
 
 use action::Action;
+use circuit_breaker_module::CircuitBreakerReason;
+use setup::{BEST_EFFORT_CALLBACK_GAS_LIMIT, BEST_EFFORT_CALL_GAS_LIMIT};
 use token_module::{AddressPercentagePair, INVALID_PERCENTAGE_SUM_OVER_ERR_MSG, PERCENTAGE_TOTAL};
 use transaction::transaction_status::TransactionStatus;
-use transaction::TxBatchSplitInFields;
 use transaction::*;
 use user_role::UserRole;
 
@@ -201,6 +203,71 @@ pub trait Multisig:
         self.tx().to(ToCaller).egld(&amount).transfer();
     }
 
+    /// Pauses EsdtSafe, MultiTransferEsdt, BridgeProxy, BridgedTokensWrapper
+    /// and the multisig itself.
+    /// Can be called by any guardian, without a quorum.
+    /// Unpausing goes through the regular owner endpoints.
+    ///
+    /// EsdtSafe and MultiTransferEsdt are expected to be owned by the multisig.
+    /// BridgeProxy and BridgedTokensWrapper are skipped when not set, and paused best-effort,
+    /// so a failure there (e.g. not owned by the multisig) does not prevent pausing the rest.
+    #[endpoint(emergencyPause)]
+    fn emergency_pause(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.get_user_role(&caller).is_guardian(),
+            "only guardians can emergency pause"
+        );
+
+        let esdt_safe_addr = self.esdt_safe_address().get();
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .pause_endpoint()
+            .sync_call();
+
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .pause_endpoint()
+            .sync_call();
+
+        let proxy_addr = self.proxy_address().get();
+        if !proxy_addr.is_zero() {
+            self.tx()
+                .to(&proxy_addr)
+                .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+                .pause_endpoint()
+                .gas(BEST_EFFORT_CALL_GAS_LIMIT)
+                .callback(
+                    self.callbacks()
+                        .best_effort_call_callback(proxy_addr.clone()),
+                )
+                .with_extra_gas_for_callback(BEST_EFFORT_CALLBACK_GAS_LIMIT)
+                .register_promise();
+        }
+
+        let wrapper_addr = self.get_bridged_tokens_wrapper_address();
+        if !wrapper_addr.is_zero() {
+            self.tx()
+                .to(&wrapper_addr)
+                .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+                .pause_endpoint()
+                .gas(BEST_EFFORT_CALL_GAS_LIMIT)
+                .callback(
+                    self.callbacks()
+                        .best_effort_call_callback(wrapper_addr.clone()),
+                )
+                .with_extra_gas_for_callback(BEST_EFFORT_CALLBACK_GAS_LIMIT)
+                .register_promise();
+        }
+
+        self.set_paused(true);
+
+        self.emergency_pause_event(&caller);
+    }
+
     // ESDT Safe SC calls
 
     /// After a batch is processed on the Ethereum side,
@@ -215,15 +282,15 @@ pub trait Multisig:
         tx_batch_status: MultiValueEncoded<TransactionStatus>,
     ) -> usize {
         let esdt_safe_addr = self.esdt_safe_address().get();
-        let call_result: OptionalValue<TxBatchSplitInFields<Self::Api>> = self
+        let call_result: OptionalValue<VersionedTxBatch<Self::Api>> = self
             .tx()
             .to(esdt_safe_addr)
             .typed(esdt_safe_proxy::EsdtSafeProxy)
-            .get_current_tx_batch()
+            .get_current_tx_batch_versioned()
             .returns(ReturnsResult)
             .sync_call();
 
-        let (current_batch_id, current_batch_transactions) = match call_result {
+        let (_, current_batch_id, current_batch_transactions) = match call_result {
             OptionalValue::Some(batch) => batch.into_tuple(),
             OptionalValue::None => sc_panic!("Current batch is empty"),
         };
@@ -236,7 +303,7 @@ pub trait Multisig:
             "Action already proposed"
         );
 
-        let current_batch_len = current_batch_transactions.raw_len() / TX_MULTIRESULT_NR_FIELDS;
+        let current_batch_len = current_batch_transactions.raw_len();
         let status_batch_len = statuses_vec.len();
         require!(
             current_batch_len == status_batch_len,
@@ -258,6 +325,26 @@ pub trait Multisig:
         action_id
     }
 
+    /// A tripped EsdtSafe circuit breaker pauses the bridge until it is reset.
+    /// Unlike a regular unpause, this requires a quorum of board members.
+    #[endpoint(proposeEsdtSafeResetCircuitBreaker)]
+    fn propose_esdt_safe_reset_circuit_breaker(&self) -> usize {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+        let trip_reason = self
+            .tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .circuit_breaker_trip_reason()
+            .returns(ReturnsResult)
+            .sync_call();
+        require!(
+            trip_reason != CircuitBreakerReason::None,
+            "EsdtSafe circuit breaker is not tripped"
+        );
+
+        self.propose_action(Action::ResetEsdtSafeCircuitBreaker)
+    }
+
     // Multi-transfer ESDT SC calls
 
     /// Proposes a batch of Ethereum -> MultiversX transfers.
@@ -277,6 +364,7 @@ pub trait Multisig:
 
         let transfers_as_eth_tx = self.transfers_multi_value_to_eth_tx_vec(transfers);
         self.require_valid_eth_tx_ids(&transfers_as_eth_tx);
+        self.require_known_tokens(&transfers_as_eth_tx);
 
         let batch_hash = self.hash_eth_tx_batch(&transfers_as_eth_tx);
         require!(
@@ -297,6 +385,23 @@ pub trait Multisig:
         action_id
     }
 
+    /// Transfers above the review threshold are held in quarantine by the MultiTransfer SC.
+    /// Once the quarantine period is over, they can be released to the receiver.
+    #[endpoint(proposeReleaseQuarantinedTransfer)]
+    fn propose_release_quarantined_transfer(&self, tx_nonce: u64) -> usize {
+        self.require_transfer_quarantined(tx_nonce);
+
+        self.propose_action(Action::ReleaseQuarantinedTransfer { tx_nonce })
+    }
+
+    /// Quarantined transfers are refunded through the regular refund batches.
+    #[endpoint(proposeRefundQuarantinedTransfer)]
+    fn propose_refund_quarantined_transfer(&self, tx_nonce: u64) -> usize {
+        self.require_transfer_quarantined(tx_nonce);
+
+        self.propose_action(Action::RefundQuarantinedTransfer { tx_nonce })
+    }
+
     /// Failed Ethereum -> MultiversX transactions are saved in the MultiTransfer SC
     /// as "refund transactions", and stored in batches, using the same mechanism as EsdtSafe.
     ///
@@ -344,6 +449,53 @@ pub trait Multisig:
         self.add_unprocessed_refund_tx_to_batch_event(tx_id);
     }
 
+    #[only_owner]
+    #[endpoint(retryUnprocessedRefundTxs)]
+    fn retry_unprocessed_refund_txs(&self) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        let nr_requeued_txs = self
+            .tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .retry_unprocessed_refund_txs()
+            .returns(ReturnsResult)
+            .sync_call();
+
+        self.retry_unprocessed_refund_txs_event(nr_requeued_txs);
+    }
+
+    /// Registers the MultiTransfer unprocessed refund transactions stored before their nonces were tracked,
+    /// so they can be retried.
+    #[only_owner]
+    #[endpoint(registerUnprocessedRefundTxs)]
+    fn register_unprocessed_refund_txs(&self, tx_nonces: MultiValueEncoded<u64>) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        let nr_registered_txs = self
+            .tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .register_unprocessed_refund_txs(tx_nonces)
+            .returns(ReturnsResult)
+            .sync_call();
+
+        self.register_unprocessed_refund_txs_event(nr_registered_txs);
+    }
+
+    /// Delivers the transfers of a batch that were halted by a circuit breaker trip in the EsdtSafe.
+    /// The breaker has to be reset first.
+    #[only_owner]
+    #[endpoint(resumeHaltedTransfers)]
+    fn resume_halted_transfers(&self, batch_id: u64) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .resume_halted_transfers(batch_id)
+            .sync_call();
+
+        self.resume_halted_transfers_event(batch_id);
+    }
+
     #[only_owner]
     #[endpoint(withdrawRefundFeesForEthereum)]
     fn withdraw_refund_fees_for_ethereum(&self, token_id: TokenIdentifier) {
@@ -370,6 +522,38 @@ pub trait Multisig:
             .sync_call();
     }
 
+    #[only_owner]
+    #[endpoint(withdrawUnrefundableDust)]
+    fn withdraw_unrefundable_dust(&self, token_id: TokenIdentifier) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+        let multisig_owner = self.blockchain().get_owner_address();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .withdraw_unrefundable_dust(token_id, multisig_owner)
+            .sync_call();
+    }
+
+    /// Refunds too small to cover the fees are kept by EsdtSafe as "unrefundable dust".
+    /// This makes part of it claimable by the given address.
+    #[only_owner]
+    #[endpoint(recreditUnrefundableDust)]
+    fn recredit_unrefundable_dust(
+        &self,
+        token_id: TokenIdentifier,
+        receiver: ManagedAddress,
+        amount: BigUint,
+    ) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .recredit_unrefundable_dust(token_id, receiver, amount)
+            .sync_call();
+    }
+
     #[only_owner]
     #[endpoint(withdrawSlashedAmount)]
     fn withdraw_slashed_amount(&self) {
@@ -467,6 +651,30 @@ pub trait Multisig:
                     .batch_transfer_esdt_token(eth_batch_id, transfers_multi)
                     .sync_call();
             }
+            Action::ResetEsdtSafeCircuitBreaker => {
+                let esdt_safe_addr = self.esdt_safe_address().get();
+                self.tx()
+                    .to(esdt_safe_addr)
+                    .typed(esdt_safe_proxy::EsdtSafeProxy)
+                    .reset_circuit_breaker()
+                    .sync_call();
+            }
+            Action::ReleaseQuarantinedTransfer { tx_nonce } => {
+                let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+                self.tx()
+                    .to(multi_transfer_esdt_addr)
+                    .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+                    .release_quarantined_transfer(tx_nonce)
+                    .sync_call();
+            }
+            Action::RefundQuarantinedTransfer { tx_nonce } => {
+                let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+                self.tx()
+                    .to(multi_transfer_esdt_addr)
+                    .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+                    .refund_quarantined_transfer(tx_nonce)
+                    .sync_call();
+            }
         }
     }
 }
@@ -97,6 +97,34 @@ where
             .original_result()
     }
 
+    /// Delivers the transfers of a batch that were halted by a circuit breaker trip in the EsdtSafe. 
+    /// The breaker has to be reset first. 
+    pub fn resume_halted_transfers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resumeHaltedTransfers")
+            .argument(&batch_id)
+            .original_result()
+    }
+
+    pub fn get_halted_transfers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, transaction::EthTransaction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHaltedTransfers")
+            .argument(&batch_id)
+            .original_result()
+    }
+
     pub fn move_refund_batch_to_safe(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -119,6 +147,38 @@ where
             .original_result()
     }
 
+    pub fn set_egld_wrapper_contract_address<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEgldWrapperContractAddress")
+            .argument(&opt_new_address)
+            .original_result()
+    }
+
+    /// When enabled, the given wrapped EGLD token is unwrapped through the EGLD wrapper contract 
+    /// and delivered as native EGLD. Only applies to user accounts, 
+    /// smart contracts keep receiving the token. 
+    pub fn set_native_egld_delivery<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        token_id: Arg0,
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setNativeEgldDelivery")
+            .argument(&token_id)
+            .argument(&enabled)
+            .original_result()
+    }
+
     pub fn set_bridge_proxy_contract_address<
         Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
@@ -145,6 +205,47 @@ where
             .original_result()
     }
 
+    /// Re-queues all the unprocessed refund transactions that are now valid, 
+    /// e.g. after the EsdtSafe account was unfrozen for the token. 
+    /// The others are kept for a later retry. 
+    ///  
+    /// Returns the number of re-queued transactions. 
+    pub fn retry_unprocessed_refund_txs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retryUnprocessedRefundTxs")
+            .original_result()
+    }
+
+    /// Registers unprocessed refund transactions stored before their nonces were tracked, 
+    /// so `retryUnprocessedRefundTxs` and `getUnprocessedRefundTxs` pick them up. 
+    /// Nonces without a stored transaction are skipped. 
+    ///  
+    /// Returns the number of registered transactions. 
+    pub fn register_unprocessed_refund_txs<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        tx_nonces: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerUnprocessedRefundTxs")
+            .argument(&tx_nonces)
+            .original_result()
+    }
+
+    pub fn get_unprocessed_refund_txs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnprocessedRefundTxs")
+            .original_result()
+    }
+
     pub fn set_esdt_safe_contract_address<
         Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
@@ -158,6 +259,180 @@ where
             .original_result()
     }
 
+    /// Transfers above the review threshold are not delivered right away, 
+    /// but held in quarantine until the board releases or refunds them. 
+    /// 0 disables the quarantine for the token. 
+    pub fn set_review_threshold<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        threshold: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReviewThreshold")
+            .argument(&token_id)
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Minimum number of blocks a transfer is held in quarantine before it can be released. 
+    pub fn set_quarantine_delay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setQuarantineDelay")
+            .argument(&delay)
+            .original_result()
+    }
+
+    /// Number of blocks an undeliverable payment can be claimed for, before it can be refunded. 
+    pub fn set_claim_expiry_duration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        duration: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClaimExpiryDuration")
+            .argument(&duration)
+            .original_result()
+    }
+
+    /// Gas given to each cross-shard delivery, and reserved for its callback, 
+    /// which turns failed deliveries into refund transactions. 
+    pub fn set_cross_shard_transfer_gas_limits<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        gas_limit: Arg0,
+        callback_gas_limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCrossShardTransferGasLimits")
+            .argument(&gas_limit)
+            .argument(&callback_gas_limit)
+            .original_result()
+    }
+
+    /// Bounds the gas a batch reserves for cross-shard deliveries. 
+    /// The ones over the limit are escrowed, for the recipients to claim. 
+    pub fn set_max_cross_shard_transfers_per_batch<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_transfers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxCrossShardTransfersPerBatch")
+            .argument(&max_transfers)
+            .original_result()
+    }
+
+    /// When enabled, smart contract calls deposited in the BridgeProxy are executed at the end of the batch, 
+    /// if the gas left over allows it. Otherwise, they stay queued until someone calls `execute`. 
+    pub fn set_auto_execute<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoExecute")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn release_quarantined_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseQuarantinedTransfer")
+            .argument(&tx_nonce)
+            .original_result()
+    }
+
+    /// Quarantined transfers can be refunded at any time, without waiting for the delay. 
+    pub fn refund_quarantined_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refundQuarantinedTransfer")
+            .argument(&tx_nonce)
+            .original_result()
+    }
+
+    pub fn is_transfer_quarantined<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isTransferQuarantined")
+            .argument(&tx_nonce)
+            .original_result()
+    }
+
+    /// Payments that could not be delivered directly (e.g. to non-payable SCs) 
+    /// are held in escrow under the receiver's address. 
+    /// The receiver can claim all of them at once, as long as they are not expired. 
+    pub fn claim_bridged_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimBridgedTokens")
+            .original_result()
+    }
+
+    /// Expired deposits are converted into refund transactions. Can be called by anyone. 
+    pub fn refund_expired_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refundExpiredDeposit")
+            .argument(&tx_nonce)
+            .original_result()
+    }
+
+    pub fn get_claimable_deposits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        receiver: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ClaimableDeposit<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableDeposits")
+            .argument(&receiver)
+            .original_result()
+    }
+
     pub fn wrapping_contract_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -167,6 +442,28 @@ where
             .original_result()
     }
 
+    pub fn egld_wrapper_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEgldWrapperContractAddress")
+            .original_result()
+    }
+
+    pub fn native_egld_delivery<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isNativeEgldDeliveryEnabled")
+            .argument(&token_id)
+            .original_result()
+    }
+
     pub fn bridge_proxy_contract_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -185,6 +482,122 @@ where
             .original_result()
     }
 
+    pub fn unprocessed_refund_txs<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, transaction::Transaction<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnprocessedRefundTx")
+            .argument(&tx_id)
+            .original_result()
+    }
+
+    pub fn halted_batch_ids(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHaltedBatchIds")
+            .original_result()
+    }
+
+    pub fn unprocessed_refund_tx_nonces(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnprocessedRefundTxNonces")
+            .original_result()
+    }
+
+    pub fn review_threshold<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReviewThreshold")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn quarantine_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuarantineDelay")
+            .original_result()
+    }
+
+    pub fn quarantined_transfers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, QuarantinedTransfer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuarantinedTransfers")
+            .original_result()
+    }
+
+    pub fn pending_cross_shard_transfers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, transaction::EthTransaction<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingCrossShardTransfers")
+            .original_result()
+    }
+
+    pub fn cross_shard_transfer_gas_limit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCrossShardTransferGasLimit")
+            .original_result()
+    }
+
+    pub fn cross_shard_transfer_callback_gas_limit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCrossShardTransferCallbackGasLimit")
+            .original_result()
+    }
+
+    pub fn max_cross_shard_transfers_per_batch(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxCrossShardTransfersPerBatch")
+            .original_result()
+    }
+
+    pub fn claim_expiry_duration(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimExpiryDuration")
+            .original_result()
+    }
+
+    pub fn auto_execute_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAutoExecuteEnabled")
+            .original_result()
+    }
+
     pub fn set_max_tx_batch_size<
         Arg0: ProxyArg<usize>,
     >(
@@ -211,6 +624,8 @@ where
             .original_result()
     }
 
+    /// Legacy layout, without the refund info and the call data of the transactions, 
+    /// see `getCurrentTxBatchVersioned`. 
     pub fn get_current_tx_batch(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue2<u64, MultiValueEncoded<Env::Api, MultiValue6<u64, u64, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, TokenIdentifier<Env::Api>, BigUint<Env::Api>>>>>> {
@@ -242,6 +657,39 @@ where
             .original_result()
     }
 
+    /// Same as `getCurrentTxBatch`, but returns a layout version tag, the batch ID, 
+    /// then full `Transaction` structs, including the refund info and the call data. 
+    pub fn get_current_tx_batch_versioned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentTxBatchVersioned")
+            .original_result()
+    }
+
+    pub fn get_first_batch_any_status_versioned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFirstBatchAnyStatusVersioned")
+            .original_result()
+    }
+
+    pub fn get_batch_versioned<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBatchVersioned")
+            .argument(&batch_id)
+            .original_result()
+    }
+
     pub fn get_batch_status<
         Arg0: ProxyArg<u64>,
     >(
@@ -301,4 +749,93 @@ where
             .argument(&token_id)
             .original_result()
     }
+
+    pub fn pause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn pause_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn unpause_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn token_paused<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isTokenPaused")
+            .argument(&token_id)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QuarantinedTransfer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub batch_id: u64,
+    pub eth_tx: transaction::EthTransaction<Api>,
+    pub release_block: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ClaimableDeposit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub batch_id: u64,
+    pub eth_tx: transaction::EthTransaction<Api>,
+    pub payment: EsdtTokenPayment<Api>,
+    pub expiry_block: u64,
 }
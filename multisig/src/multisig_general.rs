@@ -51,6 +51,7 @@ pub trait MultisigGeneralModule:
     fn add_board_member(&self, user_address: &ManagedAddress) {
         let user_id = self.user_mapper().get_or_create_user(user_address);
         let old_role = self.user_id_to_role(user_id).get();
+        require!(!old_role.is_guardian(), "guardians cannot be board members");
 
         if !old_role.is_board_member() {
             self.num_board_members().update(|value| *value += 1);
@@ -70,4 +71,27 @@ pub trait MultisigGeneralModule:
             self.user_id_to_role(user_id).set(UserRole::None);
         }
     }
+
+    fn add_guardian(&self, user_address: &ManagedAddress) {
+        let user_id = self.user_mapper().get_or_create_user(user_address);
+        let old_role = self.user_id_to_role(user_id).get();
+        require!(
+            !old_role.is_board_member(),
+            "board members cannot be guardians"
+        );
+
+        self.user_id_to_role(user_id).set(UserRole::Guardian);
+    }
+
+    fn remove_guardian(&self, user_address: &ManagedAddress) {
+        let user_id = self.user_mapper().get_user_id(user_address);
+        if user_id == 0 {
+            return;
+        }
+
+        let old_role = self.user_id_to_role(user_id).get();
+        if old_role.is_guardian() {
+            self.user_id_to_role(user_id).set(UserRole::None);
+        }
+    }
 }
@@ -154,6 +154,23 @@ where
             .original_result()
     }
 
+    /// Pauses EsdtSafe, MultiTransferEsdt, BridgeProxy, BridgedTokensWrapper 
+    /// and the multisig itself. 
+    /// Can be called by any guardian, without a quorum. 
+    /// Unpausing goes through the regular owner endpoints. 
+    ///  
+    /// EsdtSafe and MultiTransferEsdt are expected to be owned by the multisig. 
+    /// BridgeProxy and BridgedTokensWrapper are skipped when not set, and paused best-effort, 
+    /// so a failure there (e.g. not owned by the multisig) does not prevent pausing the rest. 
+    pub fn emergency_pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyPause")
+            .original_result()
+    }
+
     /// After a batch is processed on the Ethereum side, 
     /// the EsdtSafe expects a list of statuses of said transactions (success or failure). 
     ///  
@@ -175,6 +192,17 @@ where
             .original_result()
     }
 
+    /// A tripped EsdtSafe circuit breaker pauses the bridge until it is reset. 
+    /// Unlike a regular unpause, this requires a quorum of board members. 
+    pub fn propose_esdt_safe_reset_circuit_breaker(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeEsdtSafeResetCircuitBreaker")
+            .original_result()
+    }
+
     /// Proposes a batch of Ethereum -> MultiversX transfers. 
     /// Transactions have to be separated by fields, in the following order: 
     /// Sender Address, Destination Address, Token ID, Amount, Tx Nonce 
@@ -194,6 +222,35 @@ where
             .original_result()
     }
 
+    /// Transfers above the review threshold are held in quarantine by the MultiTransfer SC. 
+    /// Once the quarantine period is over, they can be released to the receiver. 
+    pub fn propose_release_quarantined_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeReleaseQuarantinedTransfer")
+            .argument(&tx_nonce)
+            .original_result()
+    }
+
+    /// Quarantined transfers are refunded through the regular refund batches. 
+    pub fn propose_refund_quarantined_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        tx_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeRefundQuarantinedTransfer")
+            .argument(&tx_nonce)
+            .original_result()
+    }
+
     /// Failed Ethereum -> MultiversX transactions are saved in the MultiTransfer SC 
     /// as "refund transactions", and stored in batches, using the same mechanism as EsdtSafe. 
     ///  
@@ -237,6 +294,45 @@ where
             .original_result()
     }
 
+    pub fn retry_unprocessed_refund_txs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("retryUnprocessedRefundTxs")
+            .original_result()
+    }
+
+    /// Registers the MultiTransfer unprocessed refund transactions stored before their nonces were tracked, 
+    /// so they can be retried. 
+    pub fn register_unprocessed_refund_txs<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        tx_nonces: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerUnprocessedRefundTxs")
+            .argument(&tx_nonces)
+            .original_result()
+    }
+
+    /// Delivers the transfers of a batch that were halted by a circuit breaker trip in the EsdtSafe. 
+    /// The breaker has to be reset first. 
+    pub fn resume_halted_transfers<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resumeHaltedTransfers")
+            .argument(&batch_id)
+            .original_result()
+    }
+
     pub fn withdraw_refund_fees_for_ethereum<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
@@ -263,6 +359,40 @@ where
             .original_result()
     }
 
+    pub fn withdraw_unrefundable_dust<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawUnrefundableDust")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Refunds too small to cover the fees are kept by EsdtSafe as "unrefundable dust". 
+    /// This makes part of it claimable by the given address. 
+    pub fn recredit_unrefundable_dust<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        receiver: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recreditUnrefundableDust")
+            .argument(&token_id)
+            .argument(&receiver)
+            .argument(&amount)
+            .original_result()
+    }
+
     pub fn withdraw_slashed_amount(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -348,6 +478,34 @@ where
             .original_result()
     }
 
+    /// Guardians can pause the whole bridge through `emergencyPause`, 
+    /// without waiting for a quorum. They cannot unpause it. 
+    pub fn add_guardian_endpoint<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addGuardian")
+            .argument(&guardian)
+            .original_result()
+    }
+
+    pub fn remove_guardian_endpoint<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeGuardian")
+            .argument(&guardian)
+            .original_result()
+    }
+
     /// Cuts a fixed amount from a board member's stake. 
     /// This should be used only in cases where the board member 
     /// is being actively malicious. 
@@ -483,6 +641,219 @@ where
             .original_result()
     }
 
+    /// Share of the bridged amount paid by the BridgeProxy to whoever executes a queued transaction. 
+    pub fn set_proxy_executor_fee_percentage<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProxyExecutorFeePercentage")
+            .argument(&percentage)
+            .original_result()
+    }
+
+    /// Bounds for the gas limit requested by Ethereum -> MultiversX smart contract calls. 
+    pub fn set_proxy_sc_call_gas_limits<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        min_gas_limit: Arg0,
+        max_gas_limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProxyScCallGasLimits")
+            .argument(&min_gas_limit)
+            .argument(&max_gas_limit)
+            .original_result()
+    }
+
+    /// Indexes the BridgeProxy transactions deposited before its pending transaction indexes existed. 
+    /// Returns the tx ID to continue from, or 0 once done. 
+    pub fn index_proxy_pending_transactions<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        start_tx_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("indexProxyPendingTransactions")
+            .argument(&start_tx_id)
+            .original_result()
+    }
+
+    /// Recovers a BridgeProxy transaction stuck as executing, once its call is known to have failed. 
+    pub fn recover_proxy_stuck_execution<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        tx_id: Arg0,
+        refund_to_ethereum: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recoverProxyStuckExecution")
+            .argument(&tx_id)
+            .argument(&refund_to_ethereum)
+            .original_result()
+    }
+
+    /// When enabled, the BridgeProxy only calls allowlisted targets. 
+    pub fn set_proxy_allowlist_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProxyAllowlistEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Allows the BridgeProxy to call `target`, optionally restricted to the given endpoints. 
+    pub fn add_proxy_allowed_target<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        target: Arg0,
+        endpoints: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addProxyAllowedTarget")
+            .argument(&target)
+            .argument(&endpoints)
+            .original_result()
+    }
+
+    pub fn remove_proxy_allowed_target<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        target: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeProxyAllowedTarget")
+            .argument(&target)
+            .original_result()
+    }
+
+    /// When enabled, third-party executions of BridgeProxy transactions pay for the requested gas. 
+    /// The price per gas unit is configured through the `changeProxy*` fee estimator endpoints. 
+    pub fn set_proxy_execution_gas_fee_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProxyExecutionGasFeeEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn change_proxy_fee_estimator_contract_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeProxyFeeEstimatorContractAddress")
+            .argument(&new_address)
+            .original_result()
+    }
+
+    pub fn change_proxy_default_price_per_gas_unit<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        new_value: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeProxyDefaultPricePerGasUnit")
+            .argument(&token_id)
+            .argument(&new_value)
+            .original_result()
+    }
+
+    pub fn change_proxy_token_ticker<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        new_ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeProxyTokenTicker")
+            .argument(&token_id)
+            .argument(&new_ticker)
+            .original_result()
+    }
+
+    pub fn pause_multi_transfer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseMultiTransfer")
+            .original_result()
+    }
+
+    pub fn unpause_multi_transfer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseMultiTransfer")
+            .original_result()
+    }
+
+    /// Pauses the token in both directions, wrapping included, 
+    /// leaving all the other tokens untouched. 
+    pub fn pause_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn unpause_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
     pub fn change_fee_estimator_contract_address<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -647,6 +1018,70 @@ where
             .original_result()
     }
 
+    /// Sets the max amount of the token that may leave the EsdtSafe in a single window 
+    /// before its circuit breaker trips. 0 disables the check for the token. 
+    pub fn esdt_safe_set_outflow_threshold<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        threshold: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("esdtSafeSetOutflowThreshold")
+            .argument(&token_id)
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Sets the duration, in blocks, of the windows in which EsdtSafe outflows are tracked. 
+    pub fn esdt_safe_set_outflow_window_duration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        window_duration: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("esdtSafeSetOutflowWindowDuration")
+            .argument(&window_duration)
+            .original_result()
+    }
+
+    /// Sets the EGLD wrapper contract, used to wrap native EGLD sent to `createTransaction`. 
+    pub fn esdt_safe_set_egld_wrapper_contract_address<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_egld_wrapper_contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("esdtSafeSetEgldWrapperContractAddress")
+            .argument(&opt_egld_wrapper_contract_address)
+            .original_result()
+    }
+
+    /// Sets the max gas a call executed on the Ethereum side may request, 
+    /// through `createTransactionWithCallData`. 0 disables outbound call data. 
+    ///  
+    /// Must only be set above 0 once the relayers read the batches through 
+    /// `getCurrentTxBatchVersioned`, as `getCurrentTxBatch` does not include the call data. 
+    pub fn esdt_safe_set_max_outbound_call_gas_limit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_gas_limit: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("esdtSafeSetMaxOutboundCallGasLimit")
+            .argument(&max_gas_limit)
+            .original_result()
+    }
+
     /// Sets the maximum bridged amount for the token for the MultiversX -> Ethereum direction. 
     /// Any attempt to transfer over this amount will be rejected. 
     pub fn esdt_safe_set_max_bridged_amount_for_token<
@@ -682,6 +1117,98 @@ where
             .original_result()
     }
 
+    /// Transfers above the review threshold are held in quarantine 
+    /// until a quorum of board members releases or refunds them. 
+    /// 0 disables the quarantine for the token. 
+    pub fn multi_transfer_esdt_set_review_threshold<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        threshold: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetReviewThreshold")
+            .argument(&token_id)
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Minimum number of blocks a transfer is held in quarantine before it can be released. 
+    pub fn multi_transfer_esdt_set_quarantine_delay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetQuarantineDelay")
+            .argument(&delay)
+            .original_result()
+    }
+
+    /// Number of blocks an undeliverable payment can be claimed for, before it can be refunded. 
+    pub fn multi_transfer_esdt_set_claim_expiry_duration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        duration: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetClaimExpiryDuration")
+            .argument(&duration)
+            .original_result()
+    }
+
+    /// Gas given to each MultiTransfer cross-shard delivery, and reserved for its callback. 
+    pub fn multi_transfer_esdt_set_cross_shard_transfer_gas_limits<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        gas_limit: Arg0,
+        callback_gas_limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetCrossShardTransferGasLimits")
+            .argument(&gas_limit)
+            .argument(&callback_gas_limit)
+            .original_result()
+    }
+
+    /// Cross-shard deliveries over this number in a batch are escrowed, for the recipients to claim. 
+    pub fn multi_transfer_esdt_set_max_cross_shard_transfers_per_batch<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_transfers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetMaxCrossShardTransfersPerBatch")
+            .argument(&max_transfers)
+            .original_result()
+    }
+
+    /// Enables executing BridgeProxy smart contract calls directly from the transfer batch. 
+    pub fn multi_transfer_esdt_set_auto_execute<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetAutoExecute")
+            .argument(&enabled)
+            .original_result()
+    }
+
     /// Any failed Ethereum -> MultiversX transactions are added into so-called "refund batches\ 
     /// This configures the size of a batch. 
     pub fn multi_transfer_esdt_set_max_refund_tx_batch_size<
@@ -732,6 +1259,38 @@ where
             .original_result()
     }
 
+    /// Sets the EGLD wrapper contract, used to deliver wrapped EGLD as native EGLD. 
+    pub fn multi_transfer_esdt_set_egld_wrapper_contract_address<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_egld_wrapper_contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetEgldWrapperContractAddress")
+            .argument(&opt_egld_wrapper_contract_address)
+            .original_result()
+    }
+
+    /// When enabled, transfers of the given wrapped EGLD token to user accounts 
+    /// are delivered as native EGLD. 
+    pub fn multi_transfer_esdt_set_native_egld_delivery<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        token_id: Arg0,
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("multiTransferEsdtSetNativeEgldDelivery")
+            .argument(&token_id)
+            .argument(&enabled)
+            .original_result()
+    }
+
     /// Minimum number of signatures needed to perform any action. 
     pub fn quorum(
         self,
@@ -875,6 +1434,8 @@ where
     /// split by fields: 
     ///  
     /// Block Nonce, Tx Nonce, Sender Address, Receiver Address, Token ID, Amount 
+    ///  
+    /// The call data of outbound calls is not included, see getCurrentTxBatchVersioned. 
     pub fn get_current_tx_batch(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue2<u64, MultiValueEncoded<Env::Api, MultiValue6<u64, u64, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, TokenIdentifier<Env::Api>, BigUint<Env::Api>>>>>> {
@@ -914,6 +1475,41 @@ where
             .original_result()
     }
 
+    /// Same as getCurrentTxBatch, but returns a layout version tag, the batch ID, 
+    /// then full Transaction structs, including the refund info and the call data. 
+    pub fn get_current_tx_batch_versioned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentTxBatchVersioned")
+            .original_result()
+    }
+
+    /// Same as getBatch, in the format of getCurrentTxBatchVersioned 
+    pub fn get_batch_versioned<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBatchVersioned")
+            .argument(&batch_id)
+            .original_result()
+    }
+
+    /// Same as getCurrentRefundBatch, in the format of getCurrentTxBatchVersioned 
+    pub fn get_current_refund_batch_versioned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue3<u8, u64, MultiValueEncoded<Env::Api, transaction::Transaction<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentRefundBatchVersioned")
+            .original_result()
+    }
+
     /// Actions are cleared after execution, so an empty entry means the action was executed already 
     /// Returns "false" if the action ID is invalid 
     pub fn was_action_executed<
@@ -1026,6 +1622,7 @@ where
     /// Indicates user rights. 
     /// `0` = no rights, 
     /// `1` = can propose. Can also sign if they have enough stake. 
+    /// `2` = can trigger an emergency pause. 
     pub fn user_role<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -1049,6 +1646,16 @@ where
             .original_result()
     }
 
+    /// Lists all guardians 
+    pub fn get_all_guardians(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllGuardians")
+            .original_result()
+    }
+
     /// Lists all board members that staked the correct amount. 
     /// A board member with not enough stake can propose, but cannot sign. 
     pub fn get_all_staked_relayers(
@@ -1164,6 +1771,7 @@ where
 pub enum UserRole {
     None,
     BoardMember,
+    Guardian,
 }
 
 #[rustfmt::skip]
@@ -1182,4 +1790,11 @@ where
         eth_batch_id: u64,
         transfers: ManagedVec<Api, transaction::EthTransaction<Api>>,
     },
+    ResetEsdtSafeCircuitBreaker,
+    ReleaseQuarantinedTransfer {
+        tx_nonce: u64,
+    },
+    RefundQuarantinedTransfer {
+        tx_nonce: u64,
+    },
 }
@@ -1,7 +1,10 @@
 use multiversx_sc::imports::*;
 
 use crate::{action::Action, esdt_safe_proxy, multi_transfer_esdt_proxy, user_role::UserRole};
-use transaction::{transaction_status::TransactionStatus, EthTxAsMultiValue, TxBatchSplitInFields};
+use transaction::{
+    transaction_status::TransactionStatus, EthTxAsMultiValue, TxBatchSplitInFields,
+    VersionedTxBatch,
+};
 
 /// Note: Additional queries can be found in the Storage module
 #[multiversx_sc::module]
@@ -12,6 +15,8 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
     /// split by fields:
     ///
     /// Block Nonce, Tx Nonce, Sender Address, Receiver Address, Token ID, Amount
+    ///
+    /// The call data of outbound calls is not included, see getCurrentTxBatchVersioned.
     #[view(getCurrentTxBatch)]
     fn get_current_tx_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
         let esdt_safe_addr = self.esdt_safe_address().get();
@@ -55,6 +60,45 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
             .sync_call()
     }
 
+    /// Same as getCurrentTxBatch, but returns a layout version tag, the batch ID,
+    /// then full Transaction structs, including the refund info and the call data.
+    #[view(getCurrentTxBatchVersioned)]
+    fn get_current_tx_batch_versioned(&self) -> OptionalValue<VersionedTxBatch<Self::Api>> {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .get_current_tx_batch_versioned()
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    /// Same as getBatch, in the format of getCurrentTxBatchVersioned
+    #[view(getBatchVersioned)]
+    fn get_batch_versioned(&self, batch_id: u64) -> OptionalValue<VersionedTxBatch<Self::Api>> {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .get_batch_versioned(batch_id)
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    /// Same as getCurrentRefundBatch, in the format of getCurrentTxBatchVersioned
+    #[view(getCurrentRefundBatchVersioned)]
+    fn get_current_refund_batch_versioned(&self) -> OptionalValue<VersionedTxBatch<Self::Api>> {
+        let multi_transfer_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .get_first_batch_any_status_versioned()
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
     /// Actions are cleared after execution, so an empty entry means the action was executed already
     /// Returns "false" if the action ID is invalid
     #[view(wasActionExecuted)]
@@ -141,6 +185,7 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
     /// Indicates user rights.
     /// `0` = no rights,
     /// `1` = can propose. Can also sign if they have enough stake.
+    /// `2` = can trigger an emergency pause.
     #[view(userRole)]
     fn user_role(&self, user: &ManagedAddress) -> UserRole {
         self.get_user_role(user)
@@ -152,6 +197,12 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
         self.get_all_users_with_role(UserRole::BoardMember)
     }
 
+    /// Lists all guardians
+    #[view(getAllGuardians)]
+    fn get_all_guardians(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.get_all_users_with_role(UserRole::Guardian)
+    }
+
     /// Lists all board members that staked the correct amount.
     /// A board member with not enough stake can propose, but cannot sign.
     #[view(getAllStakedRelayers)]
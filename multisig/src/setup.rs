@@ -2,7 +2,13 @@ use multiversx_sc::imports::*;
 
 use eth_address::EthAddress;
 
-use crate::{bridge_proxy_contract_proxy, esdt_safe_proxy, multi_transfer_esdt_proxy};
+use crate::{
+    bridge_proxy_contract_proxy, bridged_tokens_wrapper_proxy, esdt_safe_proxy,
+    multi_transfer_esdt_proxy,
+};
+
+pub const BEST_EFFORT_CALL_GAS_LIMIT: u64 = 5_000_000;
+pub const BEST_EFFORT_CALLBACK_GAS_LIMIT: u64 = 2_000_000;
 
 #[multiversx_sc::module]
 pub trait SetupModule:
@@ -56,6 +62,20 @@ pub trait SetupModule:
         );
     }
 
+    /// Guardians can pause the whole bridge through `emergencyPause`,
+    /// without waiting for a quorum. They cannot unpause it.
+    #[only_owner]
+    #[endpoint(addGuardian)]
+    fn add_guardian_endpoint(&self, guardian: ManagedAddress) {
+        self.add_guardian(&guardian);
+    }
+
+    #[only_owner]
+    #[endpoint(removeGuardian)]
+    fn remove_guardian_endpoint(&self, guardian: ManagedAddress) {
+        self.remove_guardian(&guardian);
+    }
+
     /// Cuts a fixed amount from a board member's stake.
     /// This should be used only in cases where the board member
     /// is being actively malicious.
@@ -218,6 +238,258 @@ pub trait SetupModule:
         self.unpause_bridge_proxy_event();
     }
 
+    /// Share of the bridged amount paid by the BridgeProxy to whoever executes a queued transaction.
+    #[only_owner]
+    #[endpoint(setProxyExecutorFeePercentage)]
+    fn set_proxy_executor_fee_percentage(&self, percentage: u32) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_executor_fee_percentage(percentage)
+            .sync_call();
+    }
+
+    /// Bounds for the gas limit requested by Ethereum -> MultiversX smart contract calls.
+    #[only_owner]
+    #[endpoint(setProxyScCallGasLimits)]
+    fn set_proxy_sc_call_gas_limits(&self, min_gas_limit: u64, max_gas_limit: u64) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_sc_call_gas_limits(min_gas_limit, max_gas_limit)
+            .sync_call();
+    }
+
+    /// Indexes the BridgeProxy transactions deposited before its pending transaction indexes existed.
+    /// Returns the tx ID to continue from, or 0 once done.
+    #[only_owner]
+    #[endpoint(indexProxyPendingTransactions)]
+    fn index_proxy_pending_transactions(&self, start_tx_id: usize) -> usize {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .index_pending_transactions(start_tx_id)
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    /// Recovers a BridgeProxy transaction stuck as executing, once its call is known to have failed.
+    #[only_owner]
+    #[endpoint(recoverProxyStuckExecution)]
+    fn recover_proxy_stuck_execution(&self, tx_id: usize, refund_to_ethereum: bool) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .recover_stuck_execution(tx_id, refund_to_ethereum)
+            .sync_call();
+    }
+
+    /// When enabled, the BridgeProxy only calls allowlisted targets.
+    #[only_owner]
+    #[endpoint(setProxyAllowlistEnabled)]
+    fn set_proxy_allowlist_enabled(&self, enabled: bool) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_allowlist_enabled(enabled)
+            .sync_call();
+    }
+
+    /// Allows the BridgeProxy to call `target`, optionally restricted to the given endpoints.
+    #[only_owner]
+    #[endpoint(addProxyAllowedTarget)]
+    fn add_proxy_allowed_target(
+        &self,
+        target: ManagedAddress,
+        endpoints: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .add_allowed_target(target, endpoints)
+            .sync_call();
+    }
+
+    #[only_owner]
+    #[endpoint(removeProxyAllowedTarget)]
+    fn remove_proxy_allowed_target(&self, target: ManagedAddress) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .remove_allowed_target(target)
+            .sync_call();
+    }
+
+    /// When enabled, third-party executions of BridgeProxy transactions pay for the requested gas.
+    /// The price per gas unit is configured through the `changeProxy*` fee estimator endpoints.
+    #[only_owner]
+    #[endpoint(setProxyExecutionGasFeeEnabled)]
+    fn set_proxy_execution_gas_fee_enabled(&self, enabled: bool) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_execution_gas_fee_enabled(enabled)
+            .sync_call();
+    }
+
+    #[only_owner]
+    #[endpoint(changeProxyFeeEstimatorContractAddress)]
+    fn change_proxy_fee_estimator_contract_address(&self, new_address: ManagedAddress) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_fee_estimator_contract_address(new_address)
+            .sync_call();
+    }
+
+    #[only_owner]
+    #[endpoint(changeProxyDefaultPricePerGasUnit)]
+    fn change_proxy_default_price_per_gas_unit(
+        &self,
+        token_id: TokenIdentifier,
+        new_value: BigUint,
+    ) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_default_price_per_gas_unit(token_id, new_value)
+            .sync_call();
+    }
+
+    #[only_owner]
+    #[endpoint(changeProxyTokenTicker)]
+    fn change_proxy_token_ticker(&self, token_id: TokenIdentifier, new_ticker: ManagedBuffer) {
+        let proxy_addr = self.proxy_address().get();
+
+        self.tx()
+            .to(proxy_addr)
+            .typed(bridge_proxy_contract_proxy::BridgeProxyContractProxy)
+            .set_token_ticker(token_id, new_ticker)
+            .sync_call();
+    }
+
+    #[only_owner]
+    #[endpoint(pauseMultiTransfer)]
+    fn pause_multi_transfer(&self) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .pause_endpoint()
+            .sync_call();
+
+        self.pause_multi_transfer_event();
+    }
+
+    #[only_owner]
+    #[endpoint(unpauseMultiTransfer)]
+    fn unpause_multi_transfer(&self) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .unpause_endpoint()
+            .sync_call();
+
+        self.unpause_multi_transfer_event();
+    }
+
+    /// Pauses the token in both directions, wrapping included,
+    /// leaving all the other tokens untouched.
+    #[only_owner]
+    #[endpoint(pauseToken)]
+    fn pause_token(&self, token_id: TokenIdentifier) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .pause_token(&token_id)
+            .sync_call();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .pause_token(&token_id)
+            .sync_call();
+
+        // the wrapper has its own owner, so it is updated best-effort
+        let wrapper_addr = self.get_bridged_tokens_wrapper_address();
+        if !wrapper_addr.is_zero() {
+            self.tx()
+                .to(&wrapper_addr)
+                .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+                .pause_token(&token_id)
+                .gas(BEST_EFFORT_CALL_GAS_LIMIT)
+                .callback(
+                    self.callbacks()
+                        .best_effort_call_callback(wrapper_addr.clone()),
+                )
+                .with_extra_gas_for_callback(BEST_EFFORT_CALLBACK_GAS_LIMIT)
+                .register_promise();
+        }
+
+        self.pause_token_event(&token_id);
+    }
+
+    #[only_owner]
+    #[endpoint(unpauseToken)]
+    fn unpause_token(&self, token_id: TokenIdentifier) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .unpause_token(&token_id)
+            .sync_call();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .unpause_token(&token_id)
+            .sync_call();
+
+        // the wrapper has its own owner, so it is updated best-effort
+        let wrapper_addr = self.get_bridged_tokens_wrapper_address();
+        if !wrapper_addr.is_zero() {
+            self.tx()
+                .to(&wrapper_addr)
+                .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+                .unpause_token(&token_id)
+                .gas(BEST_EFFORT_CALL_GAS_LIMIT)
+                .callback(
+                    self.callbacks()
+                        .best_effort_call_callback(wrapper_addr.clone()),
+                )
+                .with_extra_gas_for_callback(BEST_EFFORT_CALLBACK_GAS_LIMIT)
+                .register_promise();
+        }
+
+        self.unpause_token_event(&token_id);
+    }
+
     #[only_owner]
     #[endpoint(changeFeeEstimatorContractAddress)]
     fn change_fee_estimator_contract_address(&self, new_address: ManagedAddress) {
@@ -370,6 +642,66 @@ pub trait SetupModule:
             .sync_call();
     }
 
+    /// Sets the max amount of the token that may leave the EsdtSafe in a single window
+    /// before its circuit breaker trips. 0 disables the check for the token.
+    #[only_owner]
+    #[endpoint(esdtSafeSetOutflowThreshold)]
+    fn esdt_safe_set_outflow_threshold(&self, token_id: TokenIdentifier, threshold: BigUint) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .set_outflow_threshold(token_id, threshold)
+            .sync_call();
+    }
+
+    /// Sets the duration, in blocks, of the windows in which EsdtSafe outflows are tracked.
+    #[only_owner]
+    #[endpoint(esdtSafeSetOutflowWindowDuration)]
+    fn esdt_safe_set_outflow_window_duration(&self, window_duration: u64) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .set_outflow_window_duration(window_duration)
+            .sync_call();
+    }
+
+    /// Sets the EGLD wrapper contract, used to wrap native EGLD sent to `createTransaction`.
+    #[only_owner]
+    #[endpoint(esdtSafeSetEgldWrapperContractAddress)]
+    fn esdt_safe_set_egld_wrapper_contract_address(
+        &self,
+        opt_egld_wrapper_contract_address: OptionalValue<ManagedAddress>,
+    ) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .set_egld_wrapper_contract_address(opt_egld_wrapper_contract_address)
+            .sync_call();
+    }
+
+    /// Sets the max gas a call executed on the Ethereum side may request,
+    /// through `createTransactionWithCallData`. 0 disables outbound call data.
+    ///
+    /// Must only be set above 0 once the relayers read the batches through
+    /// `getCurrentTxBatchVersioned`, as `getCurrentTxBatch` does not include the call data.
+    #[only_owner]
+    #[endpoint(esdtSafeSetMaxOutboundCallGasLimit)]
+    fn esdt_safe_set_max_outbound_call_gas_limit(&self, max_gas_limit: u64) {
+        let esdt_safe_addr = self.esdt_safe_address().get();
+
+        self.tx()
+            .to(esdt_safe_addr)
+            .typed(esdt_safe_proxy::EsdtSafeProxy)
+            .set_max_outbound_call_gas_limit(max_gas_limit)
+            .sync_call();
+    }
+
     /// Sets the maximum bridged amount for the token for the MultiversX -> Ethereum direction.
     /// Any attempt to transfer over this amount will be rejected.
     #[only_owner]
@@ -404,6 +736,88 @@ pub trait SetupModule:
             .sync_call();
     }
 
+    /// Transfers above the review threshold are held in quarantine
+    /// until a quorum of board members releases or refunds them.
+    /// 0 disables the quarantine for the token.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetReviewThreshold)]
+    fn multi_transfer_esdt_set_review_threshold(
+        &self,
+        token_id: TokenIdentifier,
+        threshold: BigUint,
+    ) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_review_threshold(token_id, threshold)
+            .sync_call();
+    }
+
+    /// Minimum number of blocks a transfer is held in quarantine before it can be released.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetQuarantineDelay)]
+    fn multi_transfer_esdt_set_quarantine_delay(&self, delay: u64) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_quarantine_delay(delay)
+            .sync_call();
+    }
+
+    /// Number of blocks an undeliverable payment can be claimed for, before it can be refunded.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetClaimExpiryDuration)]
+    fn multi_transfer_esdt_set_claim_expiry_duration(&self, duration: u64) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_claim_expiry_duration(duration)
+            .sync_call();
+    }
+
+    /// Gas given to each MultiTransfer cross-shard delivery, and reserved for its callback.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetCrossShardTransferGasLimits)]
+    fn multi_transfer_esdt_set_cross_shard_transfer_gas_limits(
+        &self,
+        gas_limit: u64,
+        callback_gas_limit: u64,
+    ) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_cross_shard_transfer_gas_limits(gas_limit, callback_gas_limit)
+            .sync_call();
+    }
+
+    /// Cross-shard deliveries over this number in a batch are escrowed, for the recipients to claim.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetMaxCrossShardTransfersPerBatch)]
+    fn multi_transfer_esdt_set_max_cross_shard_transfers_per_batch(&self, max_transfers: usize) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_max_cross_shard_transfers_per_batch(max_transfers)
+            .sync_call();
+    }
+
+    /// Enables executing BridgeProxy smart contract calls directly from the transfer batch.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetAutoExecute)]
+    fn multi_transfer_esdt_set_auto_execute(&self, enabled: bool) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_auto_execute(enabled)
+            .sync_call();
+    }
+
     /// Any failed Ethereum -> MultiversX transactions are added into so-called "refund batches"
     /// This configures the size of a batch.
     #[only_owner]
@@ -456,4 +870,49 @@ pub trait SetupModule:
             .set_wrapping_contract_address(opt_wrapping_contract_address)
             .sync_call();
     }
+
+    /// Sets the EGLD wrapper contract, used to deliver wrapped EGLD as native EGLD.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetEgldWrapperContractAddress)]
+    fn multi_transfer_esdt_set_egld_wrapper_contract_address(
+        &self,
+        opt_egld_wrapper_contract_address: OptionalValue<ManagedAddress>,
+    ) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_egld_wrapper_contract_address(opt_egld_wrapper_contract_address)
+            .sync_call();
+    }
+
+    /// When enabled, transfers of the given wrapped EGLD token to user accounts
+    /// are delivered as native EGLD.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetNativeEgldDelivery)]
+    fn multi_transfer_esdt_set_native_egld_delivery(
+        &self,
+        token_id: TokenIdentifier,
+        enabled: bool,
+    ) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .set_native_egld_delivery(token_id, enabled)
+            .sync_call();
+    }
+
+    #[promises_callback]
+    fn best_effort_call_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+        sc_address: ManagedAddress,
+    ) {
+        if let ManagedAsyncCallResult::Err(err) = result {
+            self.best_effort_call_failed_event(&sc_address, err.err_code);
+        }
+    }
 }
@@ -5,6 +5,7 @@ use multiversx_sc::derive_imports::*;
 pub enum UserRole {
     None,
     BoardMember,
+    Guardian,
 }
 
 impl UserRole {
@@ -12,4 +13,9 @@ impl UserRole {
     pub fn is_board_member(&self) -> bool {
         matches!(*self, UserRole::BoardMember)
     }
+
+    #[inline(always)]
+    pub fn is_guardian(&self) -> bool {
+        matches!(*self, UserRole::Guardian)
+    }
 }
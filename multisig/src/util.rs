@@ -2,6 +2,7 @@ use multiversx_sc::imports::*;
 
 use transaction::{EthTransaction, EthTxAsMultiValue};
 
+use crate::multi_transfer_esdt_proxy;
 use crate::storage::EthBatchHash;
 use crate::user_role::UserRole;
 
@@ -74,6 +75,44 @@ pub trait UtilModule: crate::storage::StorageModule {
         }
     }
 
+    /// Every token must be mapped to an ERC20 address, in both directions.
+    /// Otherwise, the relayers referenced a token unknown to the bridge.
+    fn require_known_tokens(&self, eth_tx_vec: &ManagedVec<EthTransaction<Self::Api>>) {
+        for eth_tx in eth_tx_vec {
+            let erc20_address_mapper = self.erc20_address_for_token_id(&eth_tx.token_id);
+            require!(!erc20_address_mapper.is_empty(), "Unknown token in batch");
+
+            let erc20_address = erc20_address_mapper.get();
+            require!(
+                self.token_id_for_erc20_address(&erc20_address).get() == eth_tx.token_id,
+                "Unknown token in batch"
+            );
+        }
+    }
+
+    fn require_transfer_quarantined(&self, tx_nonce: u64) {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        let is_quarantined = self
+            .tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .is_transfer_quarantined(tx_nonce)
+            .returns(ReturnsResult)
+            .sync_call();
+        require!(is_quarantined, "Transfer is not quarantined");
+    }
+
+    /// The BridgedTokensWrapper is only known to the MultiTransferEsdt, through its config.
+    fn get_bridged_tokens_wrapper_address(&self) -> ManagedAddress {
+        let multi_transfer_esdt_addr = self.multi_transfer_esdt_address().get();
+        self.tx()
+            .to(multi_transfer_esdt_addr)
+            .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+            .wrapping_contract_address()
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
     fn hash_eth_tx_batch(
         &self,
         eth_tx_batch: &ManagedVec<EthTransaction<Self::Api>>,
@@ -23,6 +23,7 @@ use multiversx_sc::{
         Address, BigUint, CodeMetadata, ManagedAddress, ManagedBuffer, ManagedByteArray,
         ManagedOption, ManagedType, ManagedVec, MultiValueEncoded, ReturnsNewManagedAddress,
         ReturnsResult, TestAddress, TestSCAddress, TestTokenIdentifier, TokenIdentifier,
+        UserBuiltinProxy,
     },
 };
 use multiversx_sc_modules::pause::ProxyTrait;
@@ -69,6 +70,7 @@ const USER1_ADDRESS: TestAddress = TestAddress::new("user1");
 const USER2_ADDRESS: TestAddress = TestAddress::new("user2");
 const RELAYER1_ADDRESS: TestAddress = TestAddress::new("relayer1");
 const RELAYER2_ADDRESS: TestAddress = TestAddress::new("relayer2");
+const GUARDIAN_ADDRESS: TestAddress = TestAddress::new("guardian");
 
 const RANDOM_SC_ADDRESS: TestSCAddress = TestSCAddress::new("random-sc");
 
@@ -119,7 +121,9 @@ impl MultiTransferTestState {
             .balance(1_000u64)
             .account(RELAYER2_ADDRESS)
             .nonce(1)
-            .balance(1_000u64);
+            .balance(1_000u64)
+            .account(GUARDIAN_ADDRESS)
+            .nonce(1);
 
         let roles = vec![
             "ESDTRoleLocalMint".to_string(),
@@ -289,6 +293,25 @@ impl MultiTransferTestState {
             )
             .run();
 
+        for (erc20_address, token_id) in [
+            (b"0102030405060708091a", "WEGLD-123456"),
+            (b"0102030405060708091b", "ETH-123456"),
+            (b"0102030405060708091c", "ETHUSDC-afa689"),
+        ] {
+            self.world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(MULTISIG_ADDRESS)
+                .typed(multisig_proxy::MultisigProxy)
+                .add_mapping(
+                    EthAddress {
+                        raw_addr: ManagedByteArray::new_from_bytes(erc20_address),
+                    },
+                    TokenIdentifier::from(token_id),
+                )
+                .run();
+        }
+
         self.world
             .tx()
             .from(OWNER_ADDRESS)
@@ -535,9 +558,21 @@ fn ethereum_to_multiversx_relayer_call_data_several_tx_test() {
         .to(MULTISIG_ADDRESS)
         .typed(multisig_proxy::MultisigProxy)
         .perform_action_endpoint(1usize)
-        .returns(ExpectError(4, "Invalid token or amount"))
         .run();
 
+    // ETHUSDC is not whitelisted in the EsdtSafe, so the transfers are refunded
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 4);
+
     state.world.write_scenario_trace(
         "scenarios/ethereum_to_multiversx_relayer_call_data_several_tx_test.scen.json",
     );
@@ -710,9 +745,21 @@ fn ethereum_to_multiversx_relayer_query2_test() {
         .to(MULTISIG_ADDRESS)
         .typed(multisig_proxy::MultisigProxy)
         .perform_action_endpoint(1usize)
-        .returns(ExpectError(4, "Invalid token or amount"))
         .run();
 
+    // ETHUSDC is not whitelisted in the EsdtSafe, so the transfers are refunded
+    let refund_batch = state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_esdt_proxy::MultiTransferEsdtProxy)
+        .get_first_batch_any_status()
+        .returns(ReturnsResult)
+        .run();
+
+    let (_, refund_txs) = refund_batch.into_option().unwrap().into_tuple();
+    assert_eq!(refund_txs.len(), 1);
+
     state
         .world
         .write_scenario_trace("scenarios/ethereum_to_multiversx_relayer_query2_test.scen.json");
@@ -908,3 +955,243 @@ fn ethereum_to_multiversx_tx_batch_rejected_test() {
         .move_refund_batch_to_safe_from_child_contract()
         .run();
 }
+
+#[test]
+fn guardian_emergency_pause_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.multisig_deploy();
+    state.safe_deploy(Address::zero());
+    state.multi_transfer_deploy();
+    state.bridge_proxy_deploy();
+    state.bridged_tokens_wrapper_deploy();
+    state.config_multisig();
+
+    for sc_address in [BRIDGE_PROXY_ADDRESS, BRIDGED_TOKENS_WRAPPER_ADDRESS] {
+        state
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(sc_address)
+            .typed(multisig_proxy::MultisigProxy)
+            .unpause_endpoint()
+            .run();
+    }
+
+    // the wrapper keeps its own owner
+    for child_sc_address in [ESDT_SAFE_ADDRESS, BRIDGE_PROXY_ADDRESS] {
+        state
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(child_sc_address)
+            .typed(UserBuiltinProxy)
+            .change_owner_address(&MULTISIG_ADDRESS.to_managed_address())
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(GUARDIAN_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .emergency_pause()
+        .returns(ExpectError(4, "only guardians can emergency pause"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .add_guardian_endpoint(GUARDIAN_ADDRESS)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .add_guardian_endpoint(RELAYER1_ADDRESS)
+        .returns(ExpectError(4, "board members cannot be guardians"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .add_board_member_endpoint(GUARDIAN_ADDRESS)
+        .returns(ExpectError(4, "guardians cannot be board members"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(RELAYER1_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .emergency_pause()
+        .returns(ExpectError(4, "only guardians can emergency pause"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(GUARDIAN_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .emergency_pause()
+        .gas(50_000_000)
+        .run();
+
+    for sc_address in [
+        MULTISIG_ADDRESS,
+        ESDT_SAFE_ADDRESS,
+        MULTI_TRANSFER_ADDRESS,
+        BRIDGE_PROXY_ADDRESS,
+    ] {
+        let is_paused = state
+            .world
+            .query()
+            .to(sc_address)
+            .typed(multisig_proxy::MultisigProxy)
+            .paused_status()
+            .returns(ReturnsResult)
+            .run();
+        assert!(is_paused);
+    }
+
+    // pausing the wrapper failed, without reverting the rest
+    state
+        .world
+        .query()
+        .to(BRIDGED_TOKENS_WRAPPER_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .paused_status()
+        .returns(ExpectValue(false))
+        .run();
+
+    // guardians cannot unpause
+    state
+        .world
+        .tx()
+        .from(GUARDIAN_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .unpause_esdt_safe()
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn pause_token_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.multisig_deploy();
+    state.safe_deploy(Address::zero());
+    state.multi_transfer_deploy();
+    state.bridge_proxy_deploy();
+    state.bridged_tokens_wrapper_deploy();
+    state.config_multisig();
+
+    for child_sc_address in [ESDT_SAFE_ADDRESS, BRIDGED_TOKENS_WRAPPER_ADDRESS] {
+        state
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(child_sc_address)
+            .typed(UserBuiltinProxy)
+            .change_owner_address(&MULTISIG_ADDRESS.to_managed_address())
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .pause_token(WEGLD_TOKEN_ID)
+        .gas(50_000_000)
+        .run();
+
+    // the wrapper is paused as well, so MultiTransferEsdt refunds the token instead of wrapping it
+    state
+        .world
+        .query()
+        .to(BRIDGED_TOKENS_WRAPPER_ADDRESS)
+        .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+        .token_paused(WEGLD_TOKEN_ID)
+        .returns(ExpectValue(true))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTI_TRANSFER_ADDRESS)
+        .typed(multi_transfer_proxy::MultiTransferEsdtProxy)
+        .token_paused(WEGLD_TOKEN_ID)
+        .returns(ExpectValue(true))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .unpause_token(WEGLD_TOKEN_ID)
+        .gas(50_000_000)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(BRIDGED_TOKENS_WRAPPER_ADDRESS)
+        .typed(bridged_tokens_wrapper_proxy::BridgedTokensWrapperProxy)
+        .token_paused(WEGLD_TOKEN_ID)
+        .returns(ExpectValue(false))
+        .run();
+}
+
+#[test]
+fn ethereum_to_multiversx_unknown_token_rejected_test() {
+    let mut state = MultiTransferTestState::new();
+
+    state.multisig_deploy();
+    state.safe_deploy(Address::zero());
+    state.multi_transfer_deploy();
+    state.bridge_proxy_deploy();
+    state.bridged_tokens_wrapper_deploy();
+    state.config_multisig();
+
+    let eth_tx = EthTxAsMultiValue::<StaticApi>::from((
+        EthAddress {
+            raw_addr: ManagedByteArray::new_from_bytes(b"01020304050607080910"),
+        },
+        ManagedAddress::from(USER1_ADDRESS.eval_to_array()),
+        TokenIdentifier::from("UNKNOWN-123456"),
+        BigUint::from(1_000u64),
+        1u64,
+        ManagedOption::none(),
+    ));
+
+    let mut transfers: MultiValueEncoded<StaticApi, EthTxAsMultiValue<StaticApi>> =
+        MultiValueEncoded::new();
+    transfers.push(eth_tx);
+
+    state
+        .world
+        .tx()
+        .from(RELAYER1_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_multi_transfer_esdt_batch(1u32, transfers)
+        .returns(ExpectError(4, "Unknown token in batch"))
+        .run();
+}
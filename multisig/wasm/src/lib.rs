@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           74
+// Endpoints:                          116
 // Async Callback (empty):               1
-// Total number of exported functions:  77
+// Promise callbacks:                    1
+// Total number of exported functions: 120
 
 #![no_std]
 
@@ -23,19 +24,30 @@ multiversx_sc_wasm_adapter::endpoints! {
         distributeFeesFromChildContracts => distribute_fees_from_child_contracts
         stake => stake
         unstake => unstake
+        emergencyPause => emergency_pause
         proposeEsdtSafeSetCurrentTransactionBatchStatus => propose_esdt_safe_set_current_transaction_batch_status
+        proposeEsdtSafeResetCircuitBreaker => propose_esdt_safe_reset_circuit_breaker
         proposeMultiTransferEsdtBatch => propose_multi_transfer_esdt_batch
+        proposeReleaseQuarantinedTransfer => propose_release_quarantined_transfer
+        proposeRefundQuarantinedTransfer => propose_refund_quarantined_transfer
         moveRefundBatchToSafeFromChildContract => move_refund_batch_to_safe_from_child_contract
         initSupplyFromChildContract => init_supply_from_child_contract
         addUnprocessedRefundTxToBatch => add_unprocessed_refund_tx_to_batch
+        retryUnprocessedRefundTxs => retry_unprocessed_refund_txs
+        registerUnprocessedRefundTxs => register_unprocessed_refund_txs
+        resumeHaltedTransfers => resume_halted_transfers
         withdrawRefundFeesForEthereum => withdraw_refund_fees_for_ethereum
         withdrawTransactionFees => withdraw_transaction_fees
+        withdrawUnrefundableDust => withdraw_unrefundable_dust
+        recreditUnrefundableDust => recredit_unrefundable_dust
         withdrawSlashedAmount => withdraw_slashed_amount
         performAction => perform_action_endpoint
         sign => sign
         upgradeChildContractFromSource => upgrade_child_contract_from_source
         addBoardMember => add_board_member_endpoint
         removeUser => remove_user
+        addGuardian => add_guardian_endpoint
+        removeGuardian => remove_guardian_endpoint
         slashBoardMember => slash_board_member
         changeQuorum => change_quorum
         addMapping => add_mapping
@@ -46,6 +58,21 @@ multiversx_sc_wasm_adapter::endpoints! {
         initSupplyMintBurnEsdtSafe => init_supply_mint_burn_esdt_safe
         pauseProxy => pause_proxy
         unpauseProxy => unpause_proxy
+        setProxyExecutorFeePercentage => set_proxy_executor_fee_percentage
+        setProxyScCallGasLimits => set_proxy_sc_call_gas_limits
+        indexProxyPendingTransactions => index_proxy_pending_transactions
+        recoverProxyStuckExecution => recover_proxy_stuck_execution
+        setProxyAllowlistEnabled => set_proxy_allowlist_enabled
+        addProxyAllowedTarget => add_proxy_allowed_target
+        removeProxyAllowedTarget => remove_proxy_allowed_target
+        setProxyExecutionGasFeeEnabled => set_proxy_execution_gas_fee_enabled
+        changeProxyFeeEstimatorContractAddress => change_proxy_fee_estimator_contract_address
+        changeProxyDefaultPricePerGasUnit => change_proxy_default_price_per_gas_unit
+        changeProxyTokenTicker => change_proxy_token_ticker
+        pauseMultiTransfer => pause_multi_transfer
+        unpauseMultiTransfer => unpause_multi_transfer
+        pauseToken => pause_token
+        unpauseToken => unpause_token
         changeFeeEstimatorContractAddress => change_fee_estimator_contract_address
         changeMultiversXToEthGasLimit => change_multiversx_to_eth_gas_limit
         changeDefaultPricePerGasUnit => change_default_price_per_gas_unit
@@ -56,11 +83,23 @@ multiversx_sc_wasm_adapter::endpoints! {
         esdtSafeRemoveTokenFromWhitelist => esdt_safe_remove_token_from_whitelist
         esdtSafeSetMaxTxBatchSize => esdt_safe_set_max_tx_batch_size
         esdtSafeSetMaxTxBatchBlockDuration => esdt_safe_set_max_tx_batch_block_duration
+        esdtSafeSetOutflowThreshold => esdt_safe_set_outflow_threshold
+        esdtSafeSetOutflowWindowDuration => esdt_safe_set_outflow_window_duration
+        esdtSafeSetEgldWrapperContractAddress => esdt_safe_set_egld_wrapper_contract_address
+        esdtSafeSetMaxOutboundCallGasLimit => esdt_safe_set_max_outbound_call_gas_limit
         esdtSafeSetMaxBridgedAmountForToken => esdt_safe_set_max_bridged_amount_for_token
         multiTransferEsdtSetMaxBridgedAmountForToken => multi_transfer_esdt_set_max_bridged_amount_for_token
+        multiTransferEsdtSetReviewThreshold => multi_transfer_esdt_set_review_threshold
+        multiTransferEsdtSetQuarantineDelay => multi_transfer_esdt_set_quarantine_delay
+        multiTransferEsdtSetClaimExpiryDuration => multi_transfer_esdt_set_claim_expiry_duration
+        multiTransferEsdtSetCrossShardTransferGasLimits => multi_transfer_esdt_set_cross_shard_transfer_gas_limits
+        multiTransferEsdtSetMaxCrossShardTransfersPerBatch => multi_transfer_esdt_set_max_cross_shard_transfers_per_batch
+        multiTransferEsdtSetAutoExecute => multi_transfer_esdt_set_auto_execute
         multiTransferEsdtSetMaxRefundTxBatchSize => multi_transfer_esdt_set_max_refund_tx_batch_size
         multiTransferEsdtSetMaxRefundTxBatchBlockDuration => multi_transfer_esdt_set_max_refund_tx_batch_block_duration
         multiTransferEsdtSetWrappingContractAddress => multi_transfer_esdt_set_wrapping_contract_address
+        multiTransferEsdtSetEgldWrapperContractAddress => multi_transfer_esdt_set_egld_wrapper_contract_address
+        multiTransferEsdtSetNativeEgldDelivery => multi_transfer_esdt_set_native_egld_delivery
         getQuorum => quorum
         getNumBoardMembers => num_board_members
         getRequiredStakeAmount => required_stake_amount
@@ -77,6 +116,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrentTxBatch => get_current_tx_batch
         getBatch => get_batch
         getCurrentRefundBatch => get_current_refund_batch
+        getCurrentTxBatchVersioned => get_current_tx_batch_versioned
+        getBatchVersioned => get_batch_versioned
+        getCurrentRefundBatchVersioned => get_current_refund_batch_versioned
         wasActionExecuted => was_action_executed
         wasTransferActionProposed => was_transfer_action_proposed
         getActionIdForTransferBatch => get_action_id_for_transfer_batch
@@ -85,6 +127,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         signed => signed
         userRole => user_role
         getAllBoardMembers => get_all_board_members
+        getAllGuardians => get_all_guardians
         getAllStakedRelayers => get_all_staked_relayers
         getActionSignerCount => get_action_signer_count
         getActionValidSignerCount => get_action_valid_signer_count
@@ -94,6 +137,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
+        best_effort_call_callback => best_effort_call_callback
     )
 }
 